@@ -1,6 +1,6 @@
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
 
-use advent2020::day13;
+use advent2020::y2020::day13;
 
 pub fn benchmark(c: &mut Criterion) {
     let mut group = c.benchmark_group("day13::find_time");
@@ -0,0 +1,46 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+
+macro_rules! bench_parse {
+    ($group:expr, $($feature:literal => $day:ident: $input:literal),* $(,)?) => {
+        $(
+            // Each day is benchmarked when its feature is enabled
+            #[cfg(feature = $feature)]
+            {
+                let input = advent2020::y2020::get_string($input);
+                $group.bench_function(stringify!($day), |b| {
+                    b.iter(|| advent2020::y2020::$day::parse(criterion::black_box(&input)))
+                });
+            }
+        )*
+    };
+}
+
+pub fn benchmark(c: &mut Criterion) {
+    // Unused when no day is enabled
+    #[allow(unused_mut)]
+    let mut group = c.benchmark_group("parse");
+    bench_parse!(
+        group,
+        "day1" => day1: "day1.txt",
+        "day2" => day2: "day2.txt",
+        "day3" => day3: "day3.txt",
+        "day4" => day4: "day4.txt",
+        "day5" => day5: "day5.txt",
+        "day6" => day6: "day6.txt",
+        "day7" => day7: "day7.txt",
+        "day8" => day8: "day8.txt",
+        "day9" => day9: "day9.txt",
+        "day10" => day10: "day10.txt",
+        "day11" => day11: "day11.txt",
+        "day12" => day12: "day12.txt",
+        "day13" => day13: "day13.txt",
+        "day14" => day14: "day14.txt",
+        "day15" => day15: "day15.txt",
+        "day16" => day16: "day16_am.txt",
+        "day17" => day17: "day17.txt",
+    );
+    group.finish();
+}
+
+criterion_group!(benches, benchmark);
+criterion_main!(benches);
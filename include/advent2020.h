@@ -0,0 +1,30 @@
+/* Generated by advent2020::ffi::header(), do not edit */
+
+#ifndef ADVENT2020_H
+#define ADVENT2020_H
+
+#include <stddef.h>
+#include <stdint.h>
+
+#define ADVENT_OK 0
+#define ADVENT_NULL_POINTER 1
+#define ADVENT_UNKNOWN_DAY 2
+#define ADVENT_UNKNOWN_PART 3
+#define ADVENT_INVALID_UTF8 4
+#define ADVENT_SOLVER_FAILED 5
+#define ADVENT_BUFFER_TOO_SMALL 6
+#define ADVENT_PARSE_ERROR 7
+
+/* Caller-owned storage for an answer, `len` excludes the terminating NUL */
+typedef struct AdventBuffer {
+    char *data;
+    size_t capacity;
+    size_t len;
+} AdventBuffer;
+
+/* Solves `part` of `day`, returns one of the ADVENT_* codes.
+ * On ADVENT_BUFFER_TOO_SMALL, `out_buf->len` is the capacity needed, NUL included. */
+int32_t advent_solve(uint32_t day, uint32_t part, const uint8_t *input_ptr, size_t input_len,
+                     AdventBuffer *out_buf);
+
+#endif /* ADVENT2020_H */
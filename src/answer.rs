@@ -0,0 +1,262 @@
+//! Typed answers, and OCR for the block letters some puzzles draw
+
+use std::collections::HashMap;
+use std::convert::TryFrom;
+use std::fmt;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+/// The answer to one part
+///
+/// Numbers compare by value and text by its characters, a number is never
+/// equal to text. Art compares as the letters it spells when they can be read.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", content = "value", rename_all = "snake_case")]
+pub enum Answer {
+    Int(i64),
+    /// Beyond `i64`, stored as text since TOML integers are 64 bits
+    BigInt(#[serde(with = "big_int")] i128),
+    Str(String),
+    /// Block letters, `#` on and anything else off, see [`ocr`]
+    Art(String),
+}
+
+mod big_int {
+    use serde::{de, Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(value: &i128, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&value.to_string())
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<i128, D::Error> {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(de::Error::custom)
+    }
+}
+
+impl Answer {
+    pub fn int(&self) -> Option<i128> {
+        match self {
+            Self::Int(v) => Some(i128::from(*v)),
+            Self::BigInt(v) => Some(*v),
+            Self::Str(_) | Self::Art(_) => None,
+        }
+    }
+
+    /// The text of a non-numeric answer, art is read when possible
+    pub fn text(&self) -> Option<String> {
+        match self {
+            Self::Int(_) | Self::BigInt(_) => None,
+            Self::Str(s) => Some(s.clone()),
+            Self::Art(art) => Some(ocr(art).unwrap_or_else(|| art.clone())),
+        }
+    }
+}
+
+impl Default for Answer {
+    fn default() -> Self {
+        Self::Str(String::new())
+    }
+}
+
+impl PartialEq for Answer {
+    fn eq(&self, other: &Self) -> bool {
+        match (self.int(), other.int()) {
+            (Some(lhs), Some(rhs)) => lhs == rhs,
+            (None, None) => self.text() == other.text(),
+            _ => false,
+        }
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Int(v) => write!(f, "{}", v),
+            Self::BigInt(v) => write!(f, "{}", v),
+            Self::Str(_) | Self::Art(_) => write!(f, "{}", self.text().unwrap()),
+        }
+    }
+}
+
+impl From<i128> for Answer {
+    fn from(v: i128) -> Self {
+        i64::try_from(v).map_or(Self::BigInt(v), Self::Int)
+    }
+}
+
+macro_rules! from_int {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(v: $t) -> Self {
+                    i128::try_from(v).map_or_else(|_| Self::Str(v.to_string()), Self::from)
+                }
+            }
+        )*
+    };
+}
+
+from_int!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize, u128);
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Self::Str(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Self::Str(s.to_string())
+    }
+}
+
+/// Reads integers as numbers and several lines as art, never fails
+impl FromStr for Answer {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        Ok(match s.parse::<i128>() {
+            Ok(v) => Self::from(v),
+            Err(_) if s.contains('\n') => Self::Art(s.to_string()),
+            Err(_) => Self::Str(s.to_string()),
+        })
+    }
+}
+
+const GLYPH_WIDTH: usize = 4;
+const GLYPH_HEIGHT: usize = 6;
+
+/// The letters of the 4×6 font, row by row
+const FONT: [(char, &str); 18] = [
+    ('A', ".##.#..##..######..##..#"),
+    ('B', "###.#..####.#..##..####."),
+    ('C', ".##.#..##...#...#..#.##."),
+    ('E', "#####...###.#...#...####"),
+    ('F', "#####...###.#...#...#..."),
+    ('G', ".##.#..##...#.###..#.###"),
+    ('H', "#..##..######..##..##..#"),
+    ('I', ".###..#...#...#...#..###"),
+    ('J', "..##...#...#...##..#.##."),
+    ('K', "#..##.#.##..#.#.#.#.#..#"),
+    ('L', "#...#...#...#...#...####"),
+    ('O', ".##.#..##..##..##..#.##."),
+    ('P', "###.#..##..####.#...#..."),
+    ('R', "###.#..##..####.#.#.#..#"),
+    ('S', ".####...#....##....####."),
+    ('U', "#..##..##..##..##..#.##."),
+    ('Y', "#...#....#.#..#...#...#."),
+    ('Z', "####...#..#..#..#...####"),
+];
+
+/// Text drawn in the 4×6 block letters, one blank column between letters
+///
+/// `None` unless every letter is recognised.
+///
+/// ```
+/// # use advent2020::answer::ocr;
+/// let art = [
+///     "#..#.####",
+///     "#..#.#...",
+///     "####.###.",
+///     "#..#.#...",
+///     "#..#.#...",
+///     "#..#.####",
+/// ];
+/// assert_eq!(ocr(&art.join("\n")), Some("HE".to_string()));
+/// ```
+pub fn ocr(art: &str) -> Option<String> {
+    lazy_static! {
+        static ref GLYPHS: HashMap<&'static str, char> = FONT
+            .iter()
+            .map(|&(letter, glyph)| (glyph, letter))
+            .collect();
+    }
+    let rows: Vec<Vec<bool>> = art
+        .trim_matches('\n')
+        .lines()
+        .map(|line| line.trim_end().chars().map(|c| c == '#').collect())
+        .collect();
+    if rows.len() != GLYPH_HEIGHT {
+        return None;
+    }
+    let width = rows.iter().map(Vec::len).max().unwrap();
+    let lit = |x: usize, y: usize| rows[y].get(x).copied().unwrap_or(false);
+    let mut text = String::new();
+    for left in (0..width).step_by(GLYPH_WIDTH + 1) {
+        let glyph: String = (0..GLYPH_HEIGHT)
+            .flat_map(|y| (left..left + GLYPH_WIDTH).map(move |x| (x, y)))
+            .map(|(x, y)| if lit(x, y) { '#' } else { '.' })
+            .collect();
+        text.push(*GLYPHS.get(glyph.as_str())?);
+    }
+    if text.is_empty() {
+        None
+    } else {
+        Some(text)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_answer() {
+        assert_eq!(Answer::from(436usize), Answer::Int(436));
+        assert_eq!(Answer::from(u64::MAX), Answer::BigInt(u64::MAX.into()));
+        assert_eq!(Answer::Int(7), Answer::BigInt(7));
+        assert_ne!(Answer::Int(7), Answer::Str("7".to_string()));
+        assert_eq!("7".parse::<Answer>().unwrap(), Answer::Int(7));
+        assert_eq!(
+            " shiny gold ".parse::<Answer>().unwrap(),
+            Answer::from("shiny gold")
+        );
+        assert_eq!(Answer::from(-3i32), Answer::Int(-3));
+        assert_eq!(Answer::from(-3i32).to_string(), "-3");
+    }
+
+    #[test]
+    fn test_ocr() {
+        // Letters drawn from the font must read back, spaced or not at the end
+        let word: Vec<_> = FONT.iter().collect();
+        let art: String = (0..GLYPH_HEIGHT)
+            .map(|y| {
+                word.iter()
+                    .map(|(_, glyph)| &glyph[y * GLYPH_WIDTH..(y + 1) * GLYPH_WIDTH])
+                    .collect::<Vec<_>>()
+                    .join(" ")
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+        let letters: String = FONT.iter().map(|(letter, _)| letter).collect();
+        assert_eq!(ocr(&art), Some(letters.clone()));
+        assert_eq!(Answer::Art(art.clone()), Answer::from(letters.as_str()));
+        assert_eq!(Answer::Art(art.clone()).to_string(), letters);
+
+        let smudged = art.replacen('.', "#", 1);
+        assert_eq!(ocr(&smudged), None);
+        assert_eq!(Answer::Art(smudged.clone()).to_string(), smudged);
+        assert_eq!(ocr("#"), None);
+    }
+
+    #[test]
+    fn test_serde() {
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct Entry {
+            answer: Answer,
+        }
+        let entry = Entry {
+            answer: Answer::BigInt(i128::from(u64::MAX) * 2),
+        };
+        let toml = toml::to_string(&entry).unwrap();
+        assert_eq!(
+            toml,
+            "[answer]\ntype = \"big_int\"\nvalue = \"36893488147419103230\"\n"
+        );
+        assert_eq!(toml::from_str::<Entry>(&toml).unwrap(), entry);
+    }
+}
@@ -0,0 +1,484 @@
+//! Cellular automata over any cell state, topology and neighbourhood
+//!
+//! A [`Topology`] stores one generation, the [`Automaton`] keeps two of them
+//! and swaps them every step, asking the neighbourhood function which cells
+//! each cell sees and the rule what it becomes.
+
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{HashMap, HashSet};
+use std::hash::{Hash, Hasher};
+
+use crate::grid::{Grid, Pos};
+
+/// Storage for one generation of cells
+pub trait Topology: Clone + PartialEq {
+    type Pos: Clone + Eq + Hash + Send + Sync;
+    type State: Clone + PartialEq + Send + Sync;
+
+    fn get(&self, pos: &Self::Pos) -> Self::State;
+
+    fn set(&mut self, pos: &Self::Pos, state: Self::State);
+
+    /// Cells whose next state must be computed
+    fn candidates<F>(&self, neighbours: F) -> Vec<Self::Pos>
+    where
+        F: Fn(&Self::Pos) -> Vec<Self::Pos>;
+
+    /// Prepares a used buffer to receive the next generation
+    fn clear(&mut self);
+
+    /// Hash of the whole generation, for cycle detection
+    ///
+    /// Equal generations must hash alike, a match is confirmed by comparing them.
+    fn fingerprint(&self) -> u64;
+}
+
+/// Bounded 2D grid, every cell is computed each step
+impl<S> Topology for Grid<S>
+where
+    S: Clone + PartialEq + Hash + Send + Sync,
+{
+    type Pos = Pos;
+    type State = S;
+
+    fn get(&self, pos: &Pos) -> S {
+        self[*pos].clone()
+    }
+
+    fn set(&mut self, pos: &Pos, state: S) {
+        self[*pos] = state;
+    }
+
+    fn candidates<F>(&self, _neighbours: F) -> Vec<Pos>
+    where
+        F: Fn(&Pos) -> Vec<Pos>,
+    {
+        self.positions().collect()
+    }
+
+    fn clear(&mut self) {}
+
+    fn fingerprint(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        for (_, state) in self.iter() {
+            state.hash(&mut hasher);
+        }
+        hasher.finish()
+    }
+}
+
+/// Unbounded space of any dimension, only cells not in the default state are stored
+#[derive(Debug, Clone)]
+pub struct Sparse<P, S> {
+    cells: HashMap<P, S>,
+    default: S,
+}
+
+impl<P: Eq + Hash, S: PartialEq> Sparse<P, S> {
+    pub fn new(default: S) -> Self {
+        Sparse {
+            cells: HashMap::new(),
+            default,
+        }
+    }
+
+    /// Cells not in the default state
+    pub fn cells(&self) -> impl Iterator<Item = (&P, &S)> {
+        self.cells.iter()
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+}
+
+impl<P: Eq + Hash, S: PartialEq> PartialEq for Sparse<P, S> {
+    fn eq(&self, other: &Self) -> bool {
+        self.default == other.default && self.cells == other.cells
+    }
+}
+
+impl<P, S> Topology for Sparse<P, S>
+where
+    P: Clone + Eq + Hash + Send + Sync,
+    S: Clone + PartialEq + Hash + Send + Sync,
+{
+    type Pos = P;
+    type State = S;
+
+    fn get(&self, pos: &P) -> S {
+        self.cells.get(pos).unwrap_or(&self.default).clone()
+    }
+
+    fn set(&mut self, pos: &P, state: S) {
+        if state == self.default {
+            self.cells.remove(pos);
+        } else {
+            self.cells.insert(pos.clone(), state);
+        }
+    }
+
+    /// Stored cells and their neighbours, the only ones that can change
+    fn candidates<F>(&self, neighbours: F) -> Vec<P>
+    where
+        F: Fn(&P) -> Vec<P>,
+    {
+        let mut candidates: HashSet<P> = HashSet::new();
+        for pos in self.cells.keys() {
+            candidates.insert(pos.clone());
+            candidates.extend(neighbours(pos));
+        }
+        candidates.into_iter().collect()
+    }
+
+    fn clear(&mut self) {
+        self.cells.clear();
+    }
+
+    fn fingerprint(&self) -> u64 {
+        // Combined without regard to the map's iteration order
+        self.cells
+            .iter()
+            .map(|cell| {
+                let mut hasher = DefaultHasher::new();
+                cell.hash(&mut hasher);
+                hasher.finish()
+            })
+            .fold(self.cells.len() as u64, |acc, h| acc.wrapping_add(h))
+    }
+}
+
+/// Axial coordinates `(q, r)` on a hexagonal tiling, stored in a [`Sparse`]
+pub type Hex = (i64, i64);
+
+/// The 6 cells around a hexagon
+pub fn hex_neighbours(&(q, r): &Hex) -> Vec<Hex> {
+    [(1, 0), (1, -1), (0, -1), (-1, 0), (-1, 1), (0, 1)]
+        .iter()
+        .map(|(dq, dr)| (q + dq, r + dr))
+        .collect()
+}
+
+/// The 8 cells around a cell of a bounded grid
+pub fn grid_neighbours<S>(grid: &Grid<S>, &pos: &Pos) -> Vec<Pos> {
+    grid.neighbours8(pos).map(|(pos, _)| pos).collect()
+}
+
+fn next_states<T, N, R>(
+    current: &T,
+    neighbourhood: &N,
+    rule: &R,
+    positions: &[T::Pos],
+) -> Vec<T::State>
+where
+    T: Topology,
+    N: Fn(&T, &T::Pos) -> Vec<T::Pos>,
+    R: Fn(&T::State, &[T::State]) -> T::State,
+{
+    positions
+        .iter()
+        .map(|pos| {
+            let neighbours: Vec<T::State> = neighbourhood(current, pos)
+                .iter()
+                .map(|n| current.get(n))
+                .collect();
+            rule(&current.get(pos), &neighbours)
+        })
+        .collect()
+}
+
+/// Generation at which a state first appeared again
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    /// First generation of the cycle
+    pub start: usize,
+    /// Generations until it repeats, 1 for a fixed point
+    pub period: usize,
+}
+
+type Callback<'a, T> = Box<dyn FnMut(usize, &T) + 'a>;
+
+/// Double-buffered automaton
+///
+/// The neighbourhood gets the current generation, so it can look further
+/// than adjacent cells, and the rule gets a cell with its neighbours' states.
+///
+/// ```
+/// # use advent2020::automaton::{Automaton, Sparse, Topology};
+/// // Blinker in Conway's game of life
+/// let mut life = Sparse::new(false);
+/// for x in 0..3 {
+///     life.set(&(x, 0), true);
+/// }
+/// let moore = |_: &Sparse<(i64, i64), bool>, &(x, y): &(i64, i64)| {
+///     (-1..=1)
+///         .flat_map(|dx| (-1..=1).map(move |dy| (x + dx, y + dy)))
+///         .filter(|&p| p != (x, y))
+///         .collect()
+/// };
+/// let rule = |&alive: &bool, neighbours: &[bool]| {
+///     matches!((alive, neighbours.iter().filter(|&&n| n).count()), (true, 2) | (_, 3))
+/// };
+/// let mut automaton = Automaton::new(life, moore, rule);
+/// let cycle = automaton.run_until_cycle(10).unwrap();
+/// assert_eq!((cycle.start, cycle.period), (0, 2));
+/// ```
+pub struct Automaton<'a, T, N, R> {
+    current: T,
+    next: T,
+    neighbourhood: N,
+    rule: R,
+    generation: usize,
+    parallel: bool,
+    on_generation: Option<Callback<'a, T>>,
+}
+
+impl<'a, T, N, R> Automaton<'a, T, N, R>
+where
+    T: Topology + Sync,
+    N: Fn(&T, &T::Pos) -> Vec<T::Pos> + Sync,
+    R: Fn(&T::State, &[T::State]) -> T::State + Sync,
+{
+    pub fn new(initial: T, neighbourhood: N, rule: R) -> Self {
+        Automaton {
+            next: initial.clone(),
+            current: initial,
+            neighbourhood,
+            rule,
+            generation: 0,
+            parallel: false,
+            on_generation: None,
+        }
+    }
+
+    /// Spread each step over all available cores
+    pub fn parallel(mut self, parallel: bool) -> Self {
+        self.parallel = parallel;
+        self
+    }
+
+    /// Called with every new generation, e.g. to draw it
+    pub fn on_generation<F: FnMut(usize, &T) + 'a>(mut self, f: F) -> Self {
+        self.on_generation = Some(Box::new(f));
+        self
+    }
+
+    pub fn generation(&self) -> usize {
+        self.generation
+    }
+
+    pub fn current(&self) -> &T {
+        &self.current
+    }
+
+    pub fn into_current(self) -> T {
+        self.current
+    }
+
+    /// Advances one generation, returns whether any cell changed
+    pub fn step(&mut self) -> bool {
+        let candidates = self
+            .current
+            .candidates(|pos| (self.neighbourhood)(&self.current, pos));
+        let states = if self.parallel && candidates.len() > 1 {
+            let threads = std::thread::available_parallelism().map_or(1, |n| n.get());
+            let chunk_size = candidates.len().div_ceil(threads);
+            let (current, neighbourhood, rule) = (&self.current, &self.neighbourhood, &self.rule);
+            std::thread::scope(|scope| {
+                let handles: Vec<_> = candidates
+                    .chunks(chunk_size)
+                    .map(|chunk| {
+                        scope.spawn(move || next_states(current, neighbourhood, rule, chunk))
+                    })
+                    .collect();
+                handles
+                    .into_iter()
+                    .flat_map(|handle| handle.join().unwrap())
+                    .collect()
+            })
+        } else {
+            next_states(&self.current, &self.neighbourhood, &self.rule, &candidates)
+        };
+        self.next.clear();
+        let mut changed = false;
+        for (pos, state) in candidates.iter().zip(states) {
+            changed |= state != self.current.get(pos);
+            self.next.set(pos, state);
+        }
+        std::mem::swap(&mut self.current, &mut self.next);
+        self.generation += 1;
+        if let Some(f) = &mut self.on_generation {
+            f(self.generation, &self.current);
+        }
+        changed
+    }
+
+    pub fn run(&mut self, generations: usize) {
+        for _ in 0..generations {
+            self.step();
+        }
+    }
+
+    /// Steps until nothing changes, gives up after `max` steps
+    ///
+    /// Returns the generation of the fixed point.
+    pub fn run_until_stable(&mut self, max: usize) -> Option<usize> {
+        for _ in 0..max {
+            if !self.step() {
+                return Some(self.generation - 1);
+            }
+        }
+        None
+    }
+
+    /// Steps until a generation repeats, gives up after `max` steps
+    ///
+    /// Keeps every generation seen, fingerprints only narrow down which to compare.
+    pub fn run_until_cycle(&mut self, max: usize) -> Option<Cycle> {
+        let first = self.generation;
+        let mut history = vec![self.current.clone()];
+        let mut seen: HashMap<u64, Vec<usize>> = HashMap::new();
+        seen.insert(self.current.fingerprint(), vec![first]);
+        for _ in 0..max {
+            self.step();
+            let generations = seen.entry(self.current.fingerprint()).or_default();
+            if let Some(&start) = generations
+                .iter()
+                .find(|&&generation| history[generation - first] == self.current)
+            {
+                return Some(Cycle {
+                    start,
+                    period: self.generation - start,
+                });
+            }
+            generations.push(self.generation);
+            history.push(self.current.clone());
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn blinker() -> Grid<bool> {
+        Grid::from_rows(vec![
+            vec![false, false, false],
+            vec![true, true, true],
+            vec![false, false, false],
+        ])
+    }
+
+    fn life(&alive: &bool, neighbours: &[bool]) -> bool {
+        matches!(
+            (alive, neighbours.iter().filter(|&&n| n).count()),
+            (true, 2) | (_, 3)
+        )
+    }
+
+    #[test]
+    fn test_grid_blinker() {
+        let mut generations = vec![];
+        let mut automaton = Automaton::new(blinker(), grid_neighbours, life).on_generation(
+            |generation, grid: &Grid<bool>| generations.push((generation, grid[(1, 0)])),
+        );
+        assert!(automaton.step());
+        assert!(automaton.current()[(1, 0)]);
+        assert!(!automaton.current()[(0, 1)]);
+        let cycle = automaton.run_until_cycle(4).unwrap();
+        assert_eq!(
+            cycle,
+            Cycle {
+                start: 1,
+                period: 2
+            }
+        );
+        drop(automaton);
+        assert_eq!(generations, vec![(1, true), (2, false), (3, true)]);
+    }
+
+    /// Every generation hashes alike
+    #[derive(Clone, PartialEq)]
+    struct Colliding(Grid<bool>);
+
+    impl Topology for Colliding {
+        type Pos = Pos;
+        type State = bool;
+
+        fn get(&self, pos: &Pos) -> bool {
+            Topology::get(&self.0, pos)
+        }
+
+        fn set(&mut self, pos: &Pos, state: bool) {
+            Topology::set(&mut self.0, pos, state)
+        }
+
+        fn candidates<F>(&self, neighbours: F) -> Vec<Pos>
+        where
+            F: Fn(&Pos) -> Vec<Pos>,
+        {
+            self.0.candidates(neighbours)
+        }
+
+        fn clear(&mut self) {}
+
+        fn fingerprint(&self) -> u64 {
+            0
+        }
+    }
+
+    #[test]
+    fn test_fingerprint_collision() {
+        let neighbours = |grid: &Colliding, pos: &Pos| grid_neighbours(&grid.0, pos);
+        let mut automaton = Automaton::new(Colliding(blinker()), neighbours, life);
+        assert_eq!(
+            automaton.run_until_cycle(4),
+            Some(Cycle {
+                start: 0,
+                period: 2
+            })
+        );
+        let mut automaton = Automaton::new(Colliding(blinker()), neighbours, life);
+        assert_eq!(automaton.run_until_stable(4), None);
+    }
+
+    #[test]
+    fn test_fixed_point_and_parallel() {
+        // Block still life
+        let mut block = Sparse::new(false);
+        for pos in &[(0, 0), (0, 1), (1, 0), (1, 1)] {
+            block.set(pos, true);
+        }
+        let moore = |_: &Sparse<(i64, i64), bool>, &(x, y): &(i64, i64)| {
+            (-1..=1)
+                .flat_map(|dx| (-1..=1).map(move |dy| (x + dx, y + dy)))
+                .filter(|&p| p != (x, y))
+                .collect()
+        };
+        let mut automaton = Automaton::new(block, moore, life).parallel(true);
+        assert_eq!(automaton.run_until_stable(10), Some(0));
+        assert_eq!(automaton.current().len(), 4);
+    }
+
+    #[test]
+    fn test_hex() {
+        let mut tiles = Sparse::new(false);
+        tiles.set(&(0, 0), true);
+        let rule = |&black: &bool, neighbours: &[bool]| {
+            let count = neighbours.iter().filter(|&&n| n).count();
+            matches!((black, count), (true, 1) | (false, 1..=2))
+        };
+        let mut automaton = Automaton::new(
+            tiles,
+            |_: &Sparse<Hex, bool>, pos: &Hex| hex_neighbours(pos),
+            rule,
+        );
+        automaton.step();
+        assert_eq!(automaton.current().len(), 6);
+    }
+}
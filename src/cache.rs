@@ -0,0 +1,214 @@
+//! Content-addressed on-disk cache of solved parts
+//!
+//! Entries are stored under the SHA-256 of everything that can change an
+//! answer: the puzzle, its input, its parameter overrides and the build.
+
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::answer::Answer;
+use crate::memory::Stats;
+use crate::{Day, Parts};
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+/// Hex SHA-256 of `data`
+pub fn sha256(data: &[u8]) -> String {
+    hex(&Sha256::digest(data))
+}
+
+/// Fingerprint of the running executable, changes with any code change
+pub fn build_fingerprint() -> String {
+    lazy_static! {
+        static ref FINGERPRINT: String = {
+            let exe = std::env::current_exe()
+                .and_then(fs::read)
+                .unwrap_or_default();
+            sha256(&[env!("CARGO_PKG_VERSION").as_bytes(), &exe].concat())
+        };
+    }
+    FINGERPRINT.clone()
+}
+
+/// What the cached parts of a day depend on
+#[derive(Debug, Clone)]
+pub struct Key<'a> {
+    pub year: u16,
+    pub day: usize,
+    pub input: &'a str,
+    /// Parameter overrides, see [`Config::overrides`](crate::config::Config::overrides)
+    pub params: &'a str,
+    pub build: &'a str,
+}
+
+impl Key<'_> {
+    pub fn digest(&self, part: usize) -> String {
+        let mut hasher = Sha256::new();
+        for field in &[
+            self.year.to_string(),
+            self.day.to_string(),
+            part.to_string(),
+            sha256(self.input.as_bytes()),
+            self.params.to_string(),
+            self.build.to_string(),
+        ] {
+            // Length prefixed so fields can't run into each other
+            hasher.update((field.len() as u64).to_le_bytes());
+            hasher.update(field.as_bytes());
+        }
+        hex(&hasher.finalize())
+    }
+}
+
+/// A solved part as it was when first computed
+///
+/// Fields serialized as TOML tables come last.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Entry {
+    pub display: String,
+    /// The day's visual, kept with part 1
+    pub visual: Option<String>,
+    #[serde(default)]
+    pub steps: Vec<String>,
+    pub answer: Answer,
+    pub elapsed: Duration,
+}
+
+#[derive(Debug, Clone)]
+pub struct Cache {
+    dir: PathBuf,
+}
+
+impl Cache {
+    pub fn new<P: Into<PathBuf>>(dir: P) -> Self {
+        Cache { dir: dir.into() }
+    }
+
+    fn path(&self, key: &Key, part: usize) -> PathBuf {
+        self.dir.join(format!("{}.toml", key.digest(part)))
+    }
+
+    /// Missing and unreadable entries are both misses
+    pub fn get(&self, key: &Key, part: usize) -> Option<Entry> {
+        toml::from_str(&fs::read_to_string(self.path(key, part)).ok()?).ok()
+    }
+
+    pub fn put(&self, key: &Key, part: usize, entry: &Entry) -> io::Result<()> {
+        fs::create_dir_all(&self.dir)?;
+        let toml = toml::to_string(entry).map_err(io::Error::other)?;
+        // Written aside and renamed so readers never see half an entry
+        let path = self.path(key, part);
+        let partial = path.with_extension("toml.partial");
+        fs::write(&partial, toml)?;
+        fs::rename(partial, path)
+    }
+
+    /// A day rebuilt from its cached parts, without allocation stats
+    pub fn load(&self, key: &Key) -> Option<Day> {
+        let (part1, part2) = (self.get(key, 1)?, self.get(key, 2)?);
+        Some(Day {
+            answers: Parts(part1.answer, part2.answer),
+            display: Parts(part1.display, part2.display),
+            visual: part1.visual,
+            steps: Parts(part1.steps, part2.steps),
+            stats: Parts(
+                Stats {
+                    elapsed: part1.elapsed,
+                    ..Stats::default()
+                },
+                Stats {
+                    elapsed: part2.elapsed,
+                    ..Stats::default()
+                },
+            ),
+        })
+    }
+
+    pub fn store(&self, key: &Key, day: &Day) -> io::Result<()> {
+        self.put(
+            key,
+            1,
+            &Entry {
+                answer: day.answers.0.clone(),
+                display: day.display.0.clone(),
+                visual: day.visual.clone(),
+                steps: day.steps.0.clone(),
+                elapsed: day.stats.0.elapsed,
+            },
+        )?;
+        self.put(
+            key,
+            2,
+            &Entry {
+                answer: day.answers.1.clone(),
+                display: day.display.1.clone(),
+                visual: None,
+                steps: day.steps.1.clone(),
+                elapsed: day.stats.1.elapsed,
+            },
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        let dir = std::env::temp_dir().join(format!("advent-cache-test-{}", std::process::id()));
+        let cache = Cache::new(&dir);
+        let key = Key {
+            year: 2020,
+            day: 15,
+            input: "0,3,6",
+            params: "",
+            build: "test",
+        };
+        assert_eq!(cache.get(&key, 2), None);
+        let entry = Entry {
+            answer: Answer::Int(175594),
+            display: "175594 = turn 30000000".to_string(),
+            visual: Some("0,3,6,0,3,3".to_string()),
+            steps: vec!["turn 4 repeats 0".to_string()],
+            elapsed: Duration::from_millis(1500),
+        };
+        cache.put(&key, 2, &entry).unwrap();
+        assert_eq!(cache.get(&key, 2), Some(entry));
+        assert_eq!(cache.get(&key, 1), None);
+        assert!(cache.load(&key).is_none());
+        let other_input = Key {
+            input: "1,3,2",
+            ..key.clone()
+        };
+        assert_eq!(cache.get(&other_input, 2), None);
+        let other_params = Key {
+            params: "part2_turn = 2020\n",
+            ..key.clone()
+        };
+        assert_eq!(cache.get(&other_params, 2), None);
+
+        let day = Day {
+            visual: Some("visual".to_string()),
+            ..Day::new(
+                crate::Solution::new(436, "436 = turn 2020".to_string()),
+                crate::Solution::new(175594, "175594 = turn 30000000".to_string())
+                    .with_steps(vec!["turn 4 repeats 0".to_string()]),
+            )
+        };
+        cache.store(&key, &day).unwrap();
+        let cached = cache.load(&key).unwrap();
+        assert_eq!(cached.answers.0, Answer::Int(436));
+        assert_eq!(cached.display.1, day.display.1);
+        assert_eq!(cached.visual, day.visual);
+        assert_eq!(cached.steps.1, day.steps.1);
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
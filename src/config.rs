@@ -0,0 +1,325 @@
+use serde::de::DeserializeOwned;
+use std::fmt;
+use std::fs;
+use std::path::Path;
+
+use toml::value::{Table, Value};
+
+#[derive(Debug)]
+pub enum ConfigError {
+    Io(std::io::Error),
+    Toml(toml::de::Error),
+    Param(String),
+    Day(String, toml::de::Error),
+    /// `<day>.<key>` and why its value can't be used
+    Value(String, String),
+    /// A table for a day that isn't there
+    UnknownDay(String),
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(e) => write!(f, "could not read config: {}", e),
+            Self::Toml(e) => write!(f, "invalid config: {}", e),
+            Self::Param(s) => write!(f, "expected `<day>.<key>=<value>`, got `{}`", s),
+            Self::Day(day, e) => write!(f, "invalid parameters for {}: {}", day, e),
+            Self::Value(key, e) => write!(f, "invalid `{}`: {}", key, e),
+            Self::UnknownDay(day) => write!(f, "no day `{}` to take parameters", day),
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {}
+
+/// A day's typed parameters, checked beyond their types when the config is loaded
+pub trait Params: DeserializeOwned {
+    /// The first key whose value the day can't solve with, and why
+    fn validate(&self) -> Result<(), (&'static str, String)> {
+        Ok(())
+    }
+}
+
+/// Puzzle parameter overrides, one table per day
+///
+/// Each day declares its own typed `Params` with defaults,
+/// only the keys present here replace those defaults.
+/// Files hold one table per year, see [`Config::year`].
+///
+/// ```
+/// # use advent2020::config::Config;
+/// # use serde::Deserialize;
+/// #[derive(Deserialize)]
+/// #[serde(default)]
+/// struct Params {
+///     target: usize,
+///     name: String,
+/// }
+///
+/// impl Default for Params {
+///     fn default() -> Self {
+///         Params { target: 2020, name: "shiny gold".to_string() }
+///     }
+/// }
+///
+/// let mut config = Config::default();
+/// config.set("day1.target=42").unwrap();
+/// config.set("day1.name=dark olive").unwrap();
+/// let params: Params = config.params("day1").unwrap();
+/// assert_eq!(params.target, 42);
+/// assert_eq!(params.name, "dark olive");
+/// let params: Params = config.params("day2").unwrap();
+/// assert_eq!(params.target, 2020);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Config {
+    days: Table,
+}
+
+impl std::str::FromStr for Config {
+    type Err = ConfigError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Config {
+            days: s
+                .parse::<Value>()
+                .map_err(ConfigError::Toml)?
+                .try_into()
+                .map_err(ConfigError::Toml)?,
+        })
+    }
+}
+
+impl Config {
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Self, ConfigError> {
+        fs::read_to_string(path).map_err(ConfigError::Io)?.parse()
+    }
+
+    /// Day tables of a single year, from `[<year>.<day>]` sections
+    ///
+    /// A top-level table that is not a year, like the `[<day>]` sections of
+    /// older files, is an error naming its key under `year`.
+    pub fn year(&self, year: u16) -> Result<Config, ConfigError> {
+        if let Some(key) = self.days.keys().find(|key| key.parse::<u16>().is_err()) {
+            return Err(ConfigError::Value(
+                key.clone(),
+                format!(
+                    "tables are grouped by year, move it to `[{}.{}]`",
+                    year, key
+                ),
+            ));
+        }
+        Ok(Config {
+            days: match self.days.get(&year.to_string()) {
+                Some(value) => value.clone().try_into().map_err(ConfigError::Toml)?,
+                None => Table::new(),
+            },
+        })
+    }
+
+    /// Override a single parameter from a `<day>.<key>=<value>` string
+    ///
+    /// The value is read as a TOML value (`2020`, `[0, 3, 6]`, ...),
+    /// falling back to a plain string so `day7.bag=shiny gold` needs no quoting.
+    pub fn set(&mut self, param: &str) -> Result<(), ConfigError> {
+        let mut split = param.splitn(2, '=');
+        let (path, value) = match (split.next(), split.next()) {
+            (Some(path), Some(value)) => (path.trim(), value.trim()),
+            _ => return Err(ConfigError::Param(param.to_string())),
+        };
+        let mut path = path.splitn(2, '.');
+        let (day, key) = match (path.next(), path.next()) {
+            (Some(day), Some(key)) if !day.is_empty() && !key.is_empty() => (day, key),
+            _ => return Err(ConfigError::Param(param.to_string())),
+        };
+        let value = format!("value = {}", value)
+            .parse::<Value>()
+            .ok()
+            .and_then(|mut v| v.as_table_mut().and_then(|t| t.remove("value")))
+            .unwrap_or_else(|| Value::String(value.to_string()));
+        match self
+            .days
+            .entry(day.to_string())
+            .or_insert_with(|| Value::Table(Table::new()))
+        {
+            Value::Table(table) => {
+                table.insert(key.to_string(), value);
+                Ok(())
+            }
+            _ => Err(ConfigError::Param(param.to_string())),
+        }
+    }
+
+    /// Typed parameters for `day`, missing keys take the defaults of `T`
+    pub fn params<T: DeserializeOwned>(&self, day: &str) -> Result<T, ConfigError> {
+        self.days
+            .get(day)
+            .cloned()
+            .unwrap_or_else(|| Value::Table(Table::new()))
+            .try_into()
+            .map_err(|e| ConfigError::Day(day.to_string(), e))
+    }
+
+    /// Like [`params`](Self::params), also rejecting values the day can't solve with
+    pub fn checked<T: Params>(&self, day: &str) -> Result<T, ConfigError> {
+        let params: T = self.params(day)?;
+        params
+            .validate()
+            .map_err(|(key, e)| ConfigError::Value(format!("{}.{}", day, key), e))?;
+        Ok(params)
+    }
+
+    /// For days without parameters, fails on any key set for `day`
+    pub fn no_params(&self, day: &str) -> Result<(), ConfigError> {
+        match self.table(day).keys().next() {
+            Some(key) => Err(ConfigError::Value(
+                format!("{}.{}", day, key),
+                format!("{} takes no parameters", day),
+            )),
+            None => Ok(()),
+        }
+    }
+
+    /// Fails on a table for a day not in `days`, like a misspelt one
+    pub fn only_days(&self, days: &[String]) -> Result<(), ConfigError> {
+        match self.days.keys().find(|day| !days.contains(day)) {
+            Some(day) => Err(ConfigError::UnknownDay(day.clone())),
+            None => Ok(()),
+        }
+    }
+
+    /// The overrides for `day`, empty when there are none
+    pub fn table(&self, day: &str) -> Table {
+        match self.days.get(day) {
+            Some(Value::Table(table)) => table.clone(),
+            _ => Table::new(),
+        }
+    }
+
+    /// The overrides for `day` as TOML with sorted keys, empty when there are none
+    pub fn overrides(&self, day: &str) -> String {
+        self.days
+            .get(day)
+            .and_then(|value| toml::to_string(value).ok())
+            .unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    #[serde(default, deny_unknown_fields)]
+    struct Params {
+        start: Vec<usize>,
+        turn: usize,
+    }
+
+    impl Default for Params {
+        fn default() -> Self {
+            Params {
+                start: vec![0, 3, 6],
+                turn: 2020,
+            }
+        }
+    }
+
+    impl super::Params for Params {
+        fn validate(&self) -> Result<(), (&'static str, String)> {
+            match self.turn {
+                0 => Err(("turn", "turns start at 1".to_string())),
+                _ => Ok(()),
+            }
+        }
+    }
+
+    #[test]
+    fn test_defaults() {
+        let params: Params = Config::default().params("day15").unwrap();
+        assert_eq!(params, Params::default());
+    }
+
+    #[test]
+    fn test_file_and_override() {
+        let config: Config = "\
+[2020.day15]
+start = [1, 3, 2]
+turn = 10
+"
+        .parse()
+        .unwrap();
+        let mut config = config.year(2020).unwrap();
+        config.set("day15.turn = 2020").unwrap();
+        let params: Params = config.params("day15").unwrap();
+        assert_eq!(params.start, vec![1, 3, 2]);
+        assert_eq!(params.turn, 2020);
+        assert_eq!(
+            config.overrides("day15"),
+            "start = [1, 3, 2]\nturn = 2020\n"
+        );
+        assert_eq!(config.overrides("day1"), "");
+        assert_eq!(config.table("day15")["turn"], Value::Integer(2020));
+        assert!(config.table("day1").is_empty());
+    }
+
+    #[test]
+    fn test_unknown() {
+        let mut config = Config::default();
+        config.set("day2.foo=1").unwrap();
+        assert_eq!(
+            config.no_params("day2").unwrap_err().to_string(),
+            "invalid `day2.foo`: day2 takes no parameters"
+        );
+        assert!(config.no_params("day3").is_ok());
+        let days = ["day2".to_string(), "day15".to_string()];
+        assert!(config.only_days(&days).is_ok());
+        config.set("day99.x=1").unwrap();
+        assert_eq!(
+            config.only_days(&days).unwrap_err().to_string(),
+            "no day `day99` to take parameters"
+        );
+    }
+
+    #[test]
+    fn test_old_layout() {
+        let config: Config = "[day15]\nturn = 10".parse().unwrap();
+        assert_eq!(
+            config.year(2020).unwrap_err().to_string(),
+            "invalid `day15`: tables are grouped by year, move it to `[2020.day15]`"
+        );
+    }
+
+    #[test]
+    fn test_other_year() {
+        let config: Config = "[2020.day15]\nturn = 10".parse().unwrap();
+        let params: Params = config.year(2021).unwrap().params("day15").unwrap();
+        assert_eq!(params, Params::default());
+    }
+
+    #[test]
+    fn test_errors() {
+        let mut config = Config::default();
+        assert!(matches!(config.set("turn"), Err(ConfigError::Param(_))));
+        assert!(matches!(config.set("turn=4"), Err(ConfigError::Param(_))));
+        config.set("day15.turn=many").unwrap();
+        assert!(matches!(
+            config.params::<Params>("day15"),
+            Err(ConfigError::Day(_, _))
+        ));
+        let mut config = Config::default();
+        config.set("day15.other=1").unwrap();
+        assert!(matches!(
+            config.params::<Params>("day15"),
+            Err(ConfigError::Day(_, _))
+        ));
+        let mut config = Config::default();
+        config.set("day15.turn=0").unwrap();
+        assert!(config.params::<Params>("day15").is_ok());
+        assert_eq!(
+            config.checked::<Params>("day15").unwrap_err().to_string(),
+            "invalid `day15.turn`: turns start at 1"
+        );
+    }
+}
@@ -0,0 +1,171 @@
+//! Protocol for solvers written in other languages
+//!
+//! The solver gets the input on stdin and a JSON [`Request`] as its last
+//! argument, and prints a JSON object with its answers to stdout:
+//!
+//! ```text
+//! $ python3 solve.py '{"year":2020,"day":15,"params":{"part2_turn":2020}}' < day15.txt
+//! {"part1": 436, "part2": "436"}
+//! ```
+//!
+//! Answers may be numbers or strings, a missing or `null` part is unanswered.
+//! Strings holding an integer count as numbers, and several lines as art.
+
+use std::fmt;
+use std::io::{self, Write};
+use std::process::{Command, Stdio};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::answer::Answer;
+use crate::Parts;
+
+#[derive(Debug, Serialize)]
+pub struct Request {
+    pub year: u16,
+    pub day: usize,
+    /// Parameter overrides of the day, see [`Config::table`](crate::config::Config::table)
+    pub params: toml::value::Table,
+}
+
+#[derive(Debug)]
+pub enum ExternalError {
+    /// Empty command
+    Command,
+    Io(io::Error),
+    /// Non-zero exit, with what the solver wrote to stderr
+    Failed(Option<i32>, String),
+    Json(serde_json::Error),
+}
+
+impl fmt::Display for ExternalError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Command => write!(f, "empty solver command"),
+            Self::Io(e) => write!(f, "could not run the solver: {}", e),
+            Self::Failed(Some(code), stderr) => {
+                write!(f, "solver exited with {}: {}", code, stderr.trim())
+            }
+            Self::Failed(None, stderr) => write!(f, "solver was killed: {}", stderr.trim()),
+            Self::Json(e) => write!(f, "expected a JSON object with part1 and part2: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for ExternalError {}
+
+#[derive(Debug, Deserialize)]
+struct Response {
+    #[serde(default)]
+    part1: Value,
+    #[serde(default)]
+    part2: Value,
+}
+
+fn answer(value: Value) -> Option<Answer> {
+    match value {
+        Value::Null => None,
+        Value::Number(n) => Some(match (n.as_i64(), n.as_u64()) {
+            (Some(v), _) => Answer::from(v),
+            (None, Some(v)) => Answer::from(v),
+            _ => Answer::Str(n.to_string()),
+        }),
+        Value::String(s) => s.parse().ok(),
+        value => Some(Answer::Str(value.to_string())),
+    }
+}
+
+/// Answers of an external solver, `None` for unanswered parts
+#[derive(Debug)]
+pub struct Run {
+    pub answers: Parts<Option<Answer>>,
+    /// Wall time of the whole process, including its startup
+    pub elapsed: Duration,
+}
+
+/// Runs `command`, split on whitespace, on `input`
+pub fn run(command: &str, request: &Request, input: &str) -> Result<Run, ExternalError> {
+    let mut words = command.split_whitespace();
+    let program = words.next().ok_or(ExternalError::Command)?;
+    let start = Instant::now();
+    let mut child = Command::new(program)
+        .args(words)
+        .arg(serde_json::to_string(request).map_err(ExternalError::Json)?)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(ExternalError::Io)?;
+    // Written aside so a solver printing before it reads can't deadlock
+    let mut stdin = child.stdin.take().unwrap();
+    let input = input.to_string();
+    let writer = thread::spawn(move || match stdin.write_all(input.as_bytes()) {
+        // Solvers are free to ignore their input
+        Err(e) if e.kind() == io::ErrorKind::BrokenPipe => Ok(()),
+        result => result,
+    });
+    let output = child.wait_with_output().map_err(ExternalError::Io)?;
+    let elapsed = start.elapsed();
+    writer.join().unwrap().map_err(ExternalError::Io)?;
+    if !output.status.success() {
+        return Err(ExternalError::Failed(
+            output.status.code(),
+            String::from_utf8_lossy(&output.stderr).into_owned(),
+        ));
+    }
+    let response: Response = serde_json::from_slice(&output.stdout).map_err(ExternalError::Json)?;
+    Ok(Run {
+        answers: Parts(answer(response.part1), answer(response.part2)),
+        elapsed,
+    })
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Verdict {
+    Same,
+    Differs(Answer),
+    Unanswered,
+}
+
+impl Verdict {
+    pub fn new(ours: &Answer, theirs: &Option<Answer>) -> Self {
+        match theirs {
+            None => Self::Unanswered,
+            Some(theirs) if theirs == ours => Self::Same,
+            Some(theirs) => Self::Differs(theirs.clone()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_answer() {
+        let response: Response =
+            serde_json::from_str(r#"{"part1": 436, "part2": 18446744073709551615}"#).unwrap();
+        assert_eq!(answer(response.part1), Some(Answer::Int(436)));
+        assert_eq!(
+            answer(response.part2),
+            Some(Answer::BigInt(u64::MAX.into()))
+        );
+        assert_eq!(answer(Value::Null), None);
+        assert_eq!(answer(Value::from("FGJ")), Some(Answer::from("FGJ")));
+        assert_eq!(answer(Value::from(" 436\n")), Some(Answer::Int(436)));
+
+        let ours = Answer::Int(436);
+        assert_eq!(
+            Verdict::new(&ours, &Some(Answer::BigInt(436))),
+            Verdict::Same
+        );
+        assert_eq!(
+            Verdict::new(&ours, &Some(Answer::from("436 turns"))),
+            Verdict::Differs(Answer::from("436 turns"))
+        );
+        assert_eq!(Verdict::new(&ours, &None), Verdict::Unanswered);
+    }
+}
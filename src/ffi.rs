@@ -0,0 +1,158 @@
+//! C ABI over the day registry, enabled by the `ffi` feature
+//!
+//! Build the shared library with
+//! `cargo rustc --release --lib --crate-type cdylib --features ffi`, see the README.
+//! Its header, `include/advent2020.h`, is generated by [`header`] and kept
+//! up to date by the `ffi` integration test.
+
+use std::os::raw::c_char;
+use std::panic::{self, AssertUnwindSafe};
+use std::{ptr, slice, str};
+
+use crate::config::Config;
+use crate::y2020;
+use crate::{Part, SolveError};
+
+/// Result of [`advent_solve`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    Ok = 0,
+    NullPointer = 1,
+    UnknownDay = 2,
+    UnknownPart = 3,
+    InvalidUtf8 = 4,
+    /// The solver panicked
+    SolverFailed = 5,
+    /// `len` of the output buffer is set to the capacity needed, NUL included
+    BufferTooSmall = 6,
+    /// The input is not in the day's format
+    ParseError = 7,
+}
+
+impl Status {
+    pub const ALL: [Status; 8] = [
+        Self::Ok,
+        Self::NullPointer,
+        Self::UnknownDay,
+        Self::UnknownPart,
+        Self::InvalidUtf8,
+        Self::SolverFailed,
+        Self::BufferTooSmall,
+        Self::ParseError,
+    ];
+
+    fn c_name(self) -> &'static str {
+        match self {
+            Self::Ok => "ADVENT_OK",
+            Self::NullPointer => "ADVENT_NULL_POINTER",
+            Self::UnknownDay => "ADVENT_UNKNOWN_DAY",
+            Self::UnknownPart => "ADVENT_UNKNOWN_PART",
+            Self::InvalidUtf8 => "ADVENT_INVALID_UTF8",
+            Self::SolverFailed => "ADVENT_SOLVER_FAILED",
+            Self::BufferTooSmall => "ADVENT_BUFFER_TOO_SMALL",
+            Self::ParseError => "ADVENT_PARSE_ERROR",
+        }
+    }
+}
+
+/// Caller-owned storage for an answer
+#[repr(C)]
+#[derive(Debug)]
+pub struct AdventBuffer {
+    pub data: *mut c_char,
+    pub capacity: usize,
+    /// Length of the answer without its terminating NUL, or the capacity
+    /// needed on [`Status::BufferTooSmall`]
+    pub len: usize,
+}
+
+fn solve(day: u32, part: u32, input: &[u8]) -> Result<String, Status> {
+    let puzzle = y2020::get_days()
+        .into_iter()
+        .find(|puzzle| puzzle.day as u32 == day)
+        .ok_or(Status::UnknownDay)?;
+    let part = match part {
+        1 => Part::One,
+        2 => Part::Two,
+        _ => return Err(Status::UnknownPart),
+    };
+    let input = str::from_utf8(input).map_err(|_| Status::InvalidUtf8)?;
+    let answer = (puzzle.part)(input, &Config::default(), part).map_err(|e| match e {
+        SolveError::Parse(_) => Status::ParseError,
+        // Every day's defaults pass its checks
+        SolveError::Config(_) => Status::SolverFailed,
+    })?;
+    Ok(answer.to_string())
+}
+
+/// Solves `part` of `day` of the latest event, writing the answer to `out_buf`
+///
+/// Returns one of the `ADVENT_*` status codes, never unwinds into the caller.
+///
+/// # Safety
+///
+/// `input_ptr` must point to `input_len` readable bytes, `out_buf` to an
+/// [`AdventBuffer`] whose `data` has `capacity` writable bytes.
+#[no_mangle]
+pub unsafe extern "C" fn advent_solve(
+    day: u32,
+    part: u32,
+    input_ptr: *const u8,
+    input_len: usize,
+    out_buf: *mut AdventBuffer,
+) -> i32 {
+    if input_ptr.is_null() || out_buf.is_null() || (*out_buf).data.is_null() {
+        return Status::NullPointer as i32;
+    }
+    let input = slice::from_raw_parts(input_ptr, input_len);
+    // Malformed input is a parse error, a panic is a bug in the solver
+    let answer = match panic::catch_unwind(AssertUnwindSafe(|| solve(day, part, input))) {
+        Ok(Ok(answer)) => answer,
+        Ok(Err(status)) => return status as i32,
+        Err(_) => return Status::SolverFailed as i32,
+    };
+    let out = &mut *out_buf;
+    if answer.len() >= out.capacity {
+        out.len = answer.len() + 1;
+        return Status::BufferTooSmall as i32;
+    }
+    out.len = answer.len();
+    ptr::copy_nonoverlapping(answer.as_ptr(), out.data as *mut u8, answer.len());
+    *out.data.add(answer.len()) = 0;
+    Status::Ok as i32
+}
+
+/// The C header declaring this module's ABI
+pub fn header() -> String {
+    let mut header = String::from(
+        "\
+/* Generated by advent2020::ffi::header(), do not edit */
+
+#ifndef ADVENT2020_H
+#define ADVENT2020_H
+
+#include <stddef.h>
+#include <stdint.h>
+
+",
+    );
+    for status in Status::ALL.iter() {
+        header += &format!("#define {} {}\n", status.c_name(), *status as i32);
+    }
+    header += "
+/* Caller-owned storage for an answer, `len` excludes the terminating NUL */
+typedef struct AdventBuffer {
+    char *data;
+    size_t capacity;
+    size_t len;
+} AdventBuffer;
+
+/* Solves `part` of `day`, returns one of the ADVENT_* codes.
+ * On ADVENT_BUFFER_TOO_SMALL, `out_buf->len` is the capacity needed, NUL included. */
+int32_t advent_solve(uint32_t day, uint32_t part, const uint8_t *input_ptr, size_t input_len,
+                     AdventBuffer *out_buf);
+
+#endif /* ADVENT2020_H */
+";
+    header
+}
@@ -0,0 +1,451 @@
+//! Vectors, positions and directions
+//!
+//! Directions use the mathematical convention, north is `+y`.
+//! Rotations follow it too, so on a screen grid where `y` grows downwards
+//! left and right are swapped.
+
+use std::fmt;
+use std::ops::{Add, AddAssign, Index, IndexMut, Mul, Neg, Sub, SubAssign};
+
+use serde::Deserialize;
+
+/// Absolute value, `Default` standing in for zero
+fn abs<T: Copy + PartialOrd + Default + Neg<Output = T>>(v: T) -> T {
+    if v < T::default() {
+        -v
+    } else {
+        v
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Deserialize)]
+pub struct Vec2<T> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T> Vec2<T> {
+    pub const fn new(x: T, y: T) -> Self {
+        Vec2 { x, y }
+    }
+}
+
+impl<T> Vec2<T>
+where
+    T: Copy + PartialOrd + Default + Add<Output = T> + Neg<Output = T>,
+{
+    /// `|x| + |y|`
+    pub fn manhattan(self) -> T {
+        abs(self.x) + abs(self.y)
+    }
+
+    /// `max(|x|, |y|)`
+    pub fn chebyshev(self) -> T {
+        let (x, y) = (abs(self.x), abs(self.y));
+        if x < y {
+            y
+        } else {
+            x
+        }
+    }
+
+    /// Quarter turn counterclockwise
+    pub fn rotate_left(self) -> Self {
+        Vec2::new(-self.y, self.x)
+    }
+
+    /// Quarter turn clockwise
+    pub fn rotate_right(self) -> Self {
+        Vec2::new(self.y, -self.x)
+    }
+
+    /// Quarter turns counterclockwise, negative for clockwise
+    ///
+    /// ```
+    /// # use advent2020::geom::Vec2;
+    /// assert_eq!(Vec2::new(10, 4).rotate(-1), Vec2::new(4, -10));
+    /// assert_eq!(Vec2::new(10, 4).rotate(6), Vec2::new(-10, -4));
+    /// ```
+    pub fn rotate(self, quarter_turns: i32) -> Self {
+        match quarter_turns.rem_euclid(4) {
+            0 => self,
+            1 => self.rotate_left(),
+            2 => -self,
+            _ => self.rotate_right(),
+        }
+    }
+}
+
+impl<T: Add<Output = T>> Add for Vec2<T> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Vec2::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Vec2<T> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Vec2::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl<T: Neg<Output = T>> Neg for Vec2<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Vec2::new(-self.x, -self.y)
+    }
+}
+
+impl<T: Copy + Mul<Output = T>> Mul<T> for Vec2<T> {
+    type Output = Self;
+
+    fn mul(self, scale: T) -> Self {
+        Vec2::new(self.x * scale, self.y * scale)
+    }
+}
+
+impl<T: Copy + Add<Output = T>> AddAssign for Vec2<T> {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl<T: Copy + Sub<Output = T>> SubAssign for Vec2<T> {
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+impl<T> From<(T, T)> for Vec2<T> {
+    fn from((x, y): (T, T)) -> Self {
+        Vec2::new(x, y)
+    }
+}
+
+impl<T> From<Vec2<T>> for (T, T) {
+    fn from(v: Vec2<T>) -> Self {
+        (v.x, v.y)
+    }
+}
+
+impl<T> From<Vec2<T>> for VecN<T, 2> {
+    fn from(v: Vec2<T>) -> Self {
+        VecN([v.x, v.y])
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Vec2<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+/// Vector of any fixed dimension
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct VecN<T, const N: usize>(pub [T; N]);
+
+impl<T: Copy + Default, const N: usize> Default for VecN<T, N> {
+    fn default() -> Self {
+        VecN([T::default(); N])
+    }
+}
+
+impl<T: Copy + Default, const N: usize> VecN<T, N> {
+    /// Leading components from `s`, the others at their default
+    ///
+    /// ```
+    /// # use advent2020::geom::VecN;
+    /// assert_eq!(VecN::<i64, 4>::from_slice(&[1, 2]), VecN([1, 2, 0, 0]));
+    /// ```
+    pub fn from_slice(s: &[T]) -> Self {
+        assert!(s.len() <= N, "{} components for a {}D vector", s.len(), N);
+        let mut v = Self::default();
+        v.0[..s.len()].copy_from_slice(s);
+        v
+    }
+}
+
+impl<T, const N: usize> VecN<T, N> {
+    pub const fn len(&self) -> usize {
+        N
+    }
+
+    pub const fn is_empty(&self) -> bool {
+        N == 0
+    }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, T> {
+        self.0.iter()
+    }
+}
+
+impl<T, const N: usize> VecN<T, N>
+where
+    T: Copy + PartialOrd + Default + Add<Output = T> + Neg<Output = T>,
+{
+    /// Sum of the absolute components
+    pub fn manhattan(&self) -> T {
+        self.0.iter().fold(T::default(), |acc, &v| acc + abs(v))
+    }
+
+    /// Largest absolute component
+    pub fn chebyshev(&self) -> T {
+        self.0.iter().fold(T::default(), |acc, &v| {
+            let v = abs(v);
+            if acc < v {
+                v
+            } else {
+                acc
+            }
+        })
+    }
+}
+
+impl<T, const N: usize> Index<usize> for VecN<T, N> {
+    type Output = T;
+
+    fn index(&self, idx: usize) -> &T {
+        &self.0[idx]
+    }
+}
+
+impl<T, const N: usize> IndexMut<usize> for VecN<T, N> {
+    fn index_mut(&mut self, idx: usize) -> &mut T {
+        &mut self.0[idx]
+    }
+}
+
+impl<T: Copy + Add<Output = T>, const N: usize> Add for VecN<T, N> {
+    type Output = Self;
+
+    fn add(mut self, other: Self) -> Self {
+        for (a, &b) in self.0.iter_mut().zip(other.0.iter()) {
+            *a = *a + b;
+        }
+        self
+    }
+}
+
+impl<T: Copy + Sub<Output = T>, const N: usize> Sub for VecN<T, N> {
+    type Output = Self;
+
+    fn sub(mut self, other: Self) -> Self {
+        for (a, &b) in self.0.iter_mut().zip(other.0.iter()) {
+            *a = *a - b;
+        }
+        self
+    }
+}
+
+impl<T: Copy + Neg<Output = T>, const N: usize> Neg for VecN<T, N> {
+    type Output = Self;
+
+    fn neg(mut self) -> Self {
+        for a in self.0.iter_mut() {
+            *a = -*a;
+        }
+        self
+    }
+}
+
+impl<T: Copy + Mul<Output = T>, const N: usize> Mul<T> for VecN<T, N> {
+    type Output = Self;
+
+    fn mul(mut self, scale: T) -> Self {
+        for a in self.0.iter_mut() {
+            *a = *a * scale;
+        }
+        self
+    }
+}
+
+impl<T: Copy + Add<Output = T>, const N: usize> AddAssign for VecN<T, N> {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl<T, const N: usize> From<[T; N]> for VecN<T, N> {
+    fn from(a: [T; N]) -> Self {
+        VecN(a)
+    }
+}
+
+impl<T: fmt::Display, const N: usize> fmt::Display for VecN<T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "(")?;
+        for (idx, v) in self.0.iter().enumerate() {
+            if idx > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}", v)?;
+        }
+        write!(f, ")")
+    }
+}
+
+/// The four cardinal directions, clockwise from north
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize)]
+pub enum Compass {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Compass {
+    pub const ALL: [Compass; 4] = [Self::North, Self::East, Self::South, Self::West];
+
+    /// Quarter turns clockwise, negative for counterclockwise
+    pub fn turn(self, quarter_turns: i32) -> Self {
+        Self::ALL[(self as i32 + quarter_turns).rem_euclid(4) as usize]
+    }
+
+    pub fn left(self) -> Self {
+        self.turn(-1)
+    }
+
+    pub fn right(self) -> Self {
+        self.turn(1)
+    }
+
+    pub fn opposite(self) -> Self {
+        self.turn(2)
+    }
+
+    /// Unit step, north is `+y`
+    pub fn unit(self) -> Vec2<i64> {
+        match self {
+            Self::North => Vec2::new(0, 1),
+            Self::East => Vec2::new(1, 0),
+            Self::South => Vec2::new(0, -1),
+            Self::West => Vec2::new(-1, 0),
+        }
+    }
+}
+
+impl fmt::Display for Compass {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::North => "N",
+                Self::East => "E",
+                Self::South => "S",
+                Self::West => "W",
+            }
+        )
+    }
+}
+
+impl From<Compass> for Vec2<i64> {
+    fn from(direction: Compass) -> Self {
+        direction.unit()
+    }
+}
+
+/// Cardinal and diagonal directions, clockwise from north
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction8 {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Direction8 {
+    pub const ALL: [Direction8; 8] = [
+        Self::North,
+        Self::NorthEast,
+        Self::East,
+        Self::SouthEast,
+        Self::South,
+        Self::SouthWest,
+        Self::West,
+        Self::NorthWest,
+    ];
+
+    /// Eighth turns clockwise, negative for counterclockwise
+    pub fn turn(self, eighth_turns: i32) -> Self {
+        Self::ALL[(self as i32 + eighth_turns).rem_euclid(8) as usize]
+    }
+
+    /// Unit step, north is `+y`
+    pub fn unit(self) -> Vec2<i64> {
+        let (x, y) = match self {
+            Self::North => (0, 1),
+            Self::NorthEast => (1, 1),
+            Self::East => (1, 0),
+            Self::SouthEast => (1, -1),
+            Self::South => (0, -1),
+            Self::SouthWest => (-1, -1),
+            Self::West => (-1, 0),
+            Self::NorthWest => (-1, 1),
+        };
+        Vec2::new(x, y)
+    }
+}
+
+impl From<Compass> for Direction8 {
+    fn from(direction: Compass) -> Self {
+        Self::ALL[direction as usize * 2]
+    }
+}
+
+impl From<Direction8> for Vec2<i64> {
+    fn from(direction: Direction8) -> Self {
+        direction.unit()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_vec2() {
+        let mut v = Vec2::new(3, -4);
+        assert_eq!(v.manhattan(), 7);
+        assert_eq!(v.chebyshev(), 4);
+        assert_eq!(v.rotate_left().rotate_right(), v);
+        assert_eq!(v.rotate(4), v);
+        v += Vec2::new(1, 1) * 2;
+        assert_eq!(v, Vec2::new(5, -2));
+        assert_eq!(v - v, Vec2::default());
+        assert_eq!(<(i32, i32)>::from(v), (5, -2));
+    }
+
+    #[test]
+    fn test_vecn() {
+        let v = VecN([1, -2, 3, 0]);
+        assert_eq!(v + VecN([1, 1, 1, 1]), VecN([2, -1, 4, 1]));
+        assert_eq!((-v)[1], 2);
+        assert_eq!(v.manhattan(), 6);
+        assert_eq!(v.chebyshev(), 3);
+        assert_eq!(v.to_string(), "(1, -2, 3, 0)");
+        assert_eq!(VecN::from(Vec2::new(1, 2)), VecN([1, 2]));
+    }
+
+    #[test]
+    fn test_directions() {
+        assert_eq!(Compass::North.left(), Compass::West);
+        assert_eq!(Compass::West.right(), Compass::North);
+        assert_eq!(Compass::East.opposite(), Compass::West);
+        assert_eq!(Compass::East.unit().rotate_left(), Compass::North.unit());
+        assert_eq!(Direction8::from(Compass::South), Direction8::South);
+        assert_eq!(Direction8::NorthWest.turn(1), Direction8::North);
+        let sum = Direction8::ALL
+            .iter()
+            .fold(Vec2::default(), |acc, d| acc + d.unit());
+        assert_eq!(sum, Vec2::default());
+    }
+}
@@ -0,0 +1,349 @@
+//! Directed graph with interned nodes and weighted edges
+//!
+//! Nodes are interned into dense [`NodeId`]s, every edge is also stored
+//! reversed so both directions can be walked cheaply.
+
+use std::borrow::Borrow;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+
+pub type NodeId = usize;
+
+/// Which edges to follow from a node
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    /// From a node to what it points at
+    Outgoing,
+    /// From a node to what points at it
+    Incoming,
+}
+
+/// Nodes of a cycle, each with an edge to the next and the last to the first
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cycle(pub Vec<NodeId>);
+
+#[derive(Debug, Clone)]
+pub struct Graph<N, W = ()> {
+    nodes: Vec<N>,
+    ids: HashMap<N, NodeId>,
+    outgoing: Vec<Vec<(NodeId, W)>>,
+    incoming: Vec<Vec<(NodeId, W)>>,
+}
+
+impl<N, W> Default for Graph<N, W> {
+    fn default() -> Self {
+        Graph {
+            nodes: vec![],
+            ids: HashMap::new(),
+            outgoing: vec![],
+            incoming: vec![],
+        }
+    }
+}
+
+impl<N: Hash + Eq + Clone, W: Clone> Graph<N, W> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Id of `node`, adding it if it is new
+    pub fn intern(&mut self, node: N) -> NodeId {
+        if let Some(&id) = self.ids.get(&node) {
+            return id;
+        }
+        let id = self.nodes.len();
+        self.nodes.push(node.clone());
+        self.ids.insert(node, id);
+        self.outgoing.push(vec![]);
+        self.incoming.push(vec![]);
+        id
+    }
+
+    pub fn id<Q>(&self, node: &Q) -> Option<NodeId>
+    where
+        N: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.ids.get(node).copied()
+    }
+
+    pub fn node(&self, id: NodeId) -> &N {
+        &self.nodes[id]
+    }
+
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    pub fn nodes(&self) -> impl Iterator<Item = (NodeId, &N)> {
+        self.nodes.iter().enumerate()
+    }
+
+    /// Adds an edge, interning both ends
+    pub fn add_edge(&mut self, from: N, to: N, weight: W) -> (NodeId, NodeId) {
+        let (from, to) = (self.intern(from), self.intern(to));
+        self.add_edge_ids(from, to, weight);
+        (from, to)
+    }
+
+    pub fn add_edge_ids(&mut self, from: NodeId, to: NodeId, weight: W) {
+        self.outgoing[from].push((to, weight.clone()));
+        self.incoming[to].push((from, weight));
+    }
+
+    /// Neighbours of `id` with the weights of the edges to them, in insertion order
+    pub fn edges(&self, id: NodeId, direction: Direction) -> &[(NodeId, W)] {
+        match direction {
+            Direction::Outgoing => &self.outgoing[id],
+            Direction::Incoming => &self.incoming[id],
+        }
+    }
+
+    /// Nodes reachable from `start` in breadth-first order, `start` first
+    pub fn bfs(&self, start: NodeId, direction: Direction) -> Vec<NodeId> {
+        let mut seen = vec![false; self.len()];
+        let mut order = vec![];
+        let mut queue = VecDeque::new();
+        seen[start] = true;
+        queue.push_back(start);
+        while let Some(id) = queue.pop_front() {
+            order.push(id);
+            for &(next, _) in self.edges(id, direction) {
+                if !seen[next] {
+                    seen[next] = true;
+                    queue.push_back(next);
+                }
+            }
+        }
+        order
+    }
+
+    /// Nodes reachable from `start` in depth-first pre-order, `start` first
+    pub fn dfs(&self, start: NodeId, direction: Direction) -> Vec<NodeId> {
+        let mut seen = vec![false; self.len()];
+        let mut order = vec![];
+        let mut stack = vec![start];
+        while let Some(id) = stack.pop() {
+            if seen[id] {
+                continue;
+            }
+            seen[id] = true;
+            order.push(id);
+            // Reversed so the first edge is visited first
+            for &(next, _) in self.edges(id, direction).iter().rev() {
+                if !seen[next] {
+                    stack.push(next);
+                }
+            }
+        }
+        order
+    }
+
+    /// Nodes reachable from `start`, including `start`
+    pub fn reachable(&self, start: NodeId, direction: Direction) -> HashSet<NodeId> {
+        self.bfs(start, direction).into_iter().collect()
+    }
+
+    /// Every node after all nodes with an edge to it (Kahn's algorithm)
+    pub fn topological_sort(&self) -> Result<Vec<NodeId>, Cycle> {
+        let mut in_degree: Vec<usize> = self.incoming.iter().map(Vec::len).collect();
+        let mut queue: VecDeque<NodeId> =
+            (0..self.len()).filter(|&id| in_degree[id] == 0).collect();
+        let mut order = vec![];
+        while let Some(id) = queue.pop_front() {
+            order.push(id);
+            for &(next, _) in &self.outgoing[id] {
+                in_degree[next] -= 1;
+                if in_degree[next] == 0 {
+                    queue.push_back(next);
+                }
+            }
+        }
+        if order.len() == self.len() {
+            Ok(order)
+        } else {
+            Err(self
+                .find_cycle()
+                .expect("nodes left over by Kahn's algorithm"))
+        }
+    }
+
+    /// Any cycle in the graph
+    pub fn find_cycle(&self) -> Option<Cycle> {
+        #[derive(Clone, Copy, PartialEq)]
+        enum State {
+            New,
+            OnPath,
+            Done,
+        }
+        let mut state = vec![State::New; self.len()];
+        for root in 0..self.len() {
+            if state[root] != State::New {
+                continue;
+            }
+            // Path from the root, with how many edges of each node were explored
+            let mut path: Vec<(NodeId, usize)> = vec![(root, 0)];
+            state[root] = State::OnPath;
+            while let Some(&mut (id, ref mut edge)) = path.last_mut() {
+                match self.outgoing[id].get(*edge) {
+                    Some(&(next, _)) => {
+                        *edge += 1;
+                        match state[next] {
+                            State::New => {
+                                state[next] = State::OnPath;
+                                path.push((next, 0));
+                            }
+                            State::OnPath => {
+                                let start = path.iter().position(|&(id, _)| id == next).unwrap();
+                                return Some(Cycle(
+                                    path[start..].iter().map(|&(id, _)| id).collect(),
+                                ));
+                            }
+                            State::Done => {}
+                        }
+                    }
+                    None => {
+                        state[id] = State::Done;
+                        path.pop();
+                    }
+                }
+            }
+        }
+        None
+    }
+
+    /// Folds the graph below `start` bottom-up, visiting each node once
+    ///
+    /// `f` gets a node and, for each outgoing edge, the folded value of its
+    /// target along with the edge weight. Fails on a cycle reachable from `start`.
+    ///
+    /// ```
+    /// # use advent2020::graph::Graph;
+    /// let mut graph = Graph::new();
+    /// graph.add_edge("a", "b", 2);
+    /// graph.add_edge("a", "c", 3);
+    /// graph.add_edge("b", "c", 4);
+    /// // Number of paths from each node to a leaf, weighted by the edge products
+    /// let paths = graph
+    ///     .aggregate(graph.id("a").unwrap(), |_, children: &[(usize, &usize)]| {
+    ///         children.iter().map(|(paths, &weight)| paths * weight).sum::<usize>().max(1)
+    ///     })
+    ///     .unwrap();
+    /// assert_eq!(paths, 2 * 4 + 3);
+    /// ```
+    pub fn aggregate<T, F>(&self, start: NodeId, mut f: F) -> Result<T, Cycle>
+    where
+        T: Clone,
+        F: FnMut(&N, &[(T, &W)]) -> T,
+    {
+        let mut memo: Vec<Option<T>> = vec![None; self.len()];
+        let mut on_path = vec![false; self.len()];
+        let mut path: Vec<(NodeId, usize)> = vec![(start, 0)];
+        on_path[start] = true;
+        while let Some(&mut (id, ref mut edge)) = path.last_mut() {
+            match self.outgoing[id].get(*edge) {
+                Some(&(next, _)) => {
+                    *edge += 1;
+                    if on_path[next] {
+                        let start = path.iter().position(|&(id, _)| id == next).unwrap();
+                        return Err(Cycle(path[start..].iter().map(|&(id, _)| id).collect()));
+                    }
+                    if memo[next].is_none() {
+                        on_path[next] = true;
+                        path.push((next, 0));
+                    }
+                }
+                None => {
+                    let children: Vec<(T, &W)> = self.outgoing[id]
+                        .iter()
+                        .map(|(next, weight)| (memo[*next].clone().unwrap(), weight))
+                        .collect();
+                    memo[id] = Some(f(&self.nodes[id], &children));
+                    on_path[id] = false;
+                    path.pop();
+                }
+            }
+        }
+        Ok(memo[start].take().unwrap())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn example() -> Graph<&'static str> {
+        let mut graph = Graph::new();
+        for (from, to) in &[("a", "b"), ("a", "c"), ("b", "d"), ("c", "d"), ("d", "e")] {
+            graph.add_edge(*from, *to, ());
+        }
+        graph.intern("f");
+        graph
+    }
+
+    fn names<'a>(graph: &Graph<&'a str>, ids: &[NodeId]) -> Vec<&'a str> {
+        ids.iter().map(|&id| *graph.node(id)).collect()
+    }
+
+    #[test]
+    fn test_traversal() {
+        let graph = example();
+        let a = graph.id("a").unwrap();
+        let d = graph.id("d").unwrap();
+        assert_eq!(graph.len(), 6);
+        assert_eq!(
+            names(&graph, &graph.bfs(a, Direction::Outgoing)),
+            vec!["a", "b", "c", "d", "e"]
+        );
+        assert_eq!(
+            names(&graph, &graph.dfs(a, Direction::Outgoing)),
+            vec!["a", "b", "d", "e", "c"]
+        );
+        assert_eq!(graph.reachable(d, Direction::Incoming).len(), 4);
+        assert_eq!(graph.edges(d, Direction::Incoming).len(), 2);
+    }
+
+    #[test]
+    fn test_topological_sort() {
+        let mut graph = example();
+        let order = graph.topological_sort().unwrap();
+        let position = |name| {
+            order
+                .iter()
+                .position(|&id| *graph.node(id) == name)
+                .unwrap()
+        };
+        assert!(position("a") < position("b"));
+        assert!(position("c") < position("d"));
+        assert!(position("d") < position("e"));
+        assert_eq!(graph.find_cycle(), None);
+
+        graph.add_edge("e", "b", ());
+        let Cycle(cycle) = graph.topological_sort().unwrap_err();
+        assert_eq!(names(&graph, &cycle), vec!["b", "d", "e"]);
+    }
+
+    #[test]
+    fn test_aggregate() {
+        let mut graph = example();
+        let a = graph.id("a").unwrap();
+        let mut calls = 0;
+        let paths = graph.aggregate(a, |_, children: &[(usize, &())]| {
+            calls += 1;
+            children
+                .iter()
+                .map(|(paths, _)| paths)
+                .sum::<usize>()
+                .max(1)
+        });
+        assert_eq!(paths, Ok(2));
+        assert_eq!(calls, 5);
+
+        graph.add_edge("e", "a", ());
+        assert!(graph.aggregate(a, |_, _: &[(usize, &())]| 0).is_err());
+    }
+}
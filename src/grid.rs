@@ -0,0 +1,248 @@
+//! Rectangular 2D grid with flat, row-major storage
+//!
+//! Positions are `(x, y)` with `x` the column and `y` the row, `(0, 0)` top left.
+//! Since `y` grows downwards, [`Compass::North`](crate::geom::Compass) steps down
+//! a row; neighbourhoods are symmetric so this only matters for named directions.
+
+use std::convert::TryFrom;
+use std::fmt;
+use std::ops::{Index, IndexMut};
+use std::str::FromStr;
+
+use crate::geom::{Compass, Direction8, Vec2};
+use crate::parse::{self, Cell, ParseError};
+
+pub type Pos = (usize, usize);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self {
+        Grid {
+            cells: vec![fill; width * height],
+            width,
+            height,
+        }
+    }
+}
+
+impl<T> Grid<T> {
+    /// Panics if the rows are not all the same length
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Self {
+        let height = rows.len();
+        let width = rows.first().map_or(0, Vec::len);
+        assert!(
+            rows.iter().all(|row| row.len() == width),
+            "rows of a grid must have the same length"
+        );
+        Grid {
+            cells: rows.into_iter().flatten().collect(),
+            width,
+            height,
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, (x, y): Pos) -> Option<&T> {
+        if x < self.width && y < self.height {
+            self.cells.get(y * self.width + x)
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, (x, y): Pos) -> Option<&mut T> {
+        if x < self.width && y < self.height {
+            self.cells.get_mut(y * self.width + x)
+        } else {
+            None
+        }
+    }
+
+    /// The grid repeats endlessly in every direction
+    ///
+    /// ```
+    /// # use advent2020::grid::Grid;
+    /// let grid = Grid::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6]]);
+    /// assert_eq!(grid.get_wrapping(4, 1), &5);
+    /// assert_eq!(grid.get_wrapping(-1, -1), &6);
+    /// ```
+    pub fn get_wrapping(&self, x: i64, y: i64) -> &T {
+        let x = x.rem_euclid(i64::try_from(self.width).unwrap());
+        let y = y.rem_euclid(i64::try_from(self.height).unwrap());
+        &self[(usize::try_from(x).unwrap(), usize::try_from(y).unwrap())]
+    }
+
+    /// `pos` moved by `delta`, if that is still inside the grid
+    pub fn offset(&self, (x, y): Pos, delta: impl Into<Vec2<i64>>) -> Option<Pos> {
+        let delta = delta.into();
+        let x = usize::try_from(i64::try_from(x).ok()? + delta.x).ok()?;
+        let y = usize::try_from(i64::try_from(y).ok()? + delta.y).ok()?;
+        if x < self.width && y < self.height {
+            Some((x, y))
+        } else {
+            None
+        }
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    /// All cells in row-major order
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (Pos, &mut T)> {
+        self.positions().zip(self.cells.iter_mut())
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn row(&self, y: usize) -> Option<&[T]> {
+        if y < self.height {
+            Some(&self.cells[y * self.width..(y + 1) * self.width])
+        } else {
+            None
+        }
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        let end = if x < self.width { self.cells.len() } else { 0 };
+        self.cells[..end].iter().skip(x).step_by(self.width.max(1))
+    }
+
+    fn neighbours<'a, D: Into<Vec2<i64>> + Copy + 'a>(
+        &'a self,
+        pos: Pos,
+        directions: &'a [D],
+    ) -> impl Iterator<Item = (Pos, &'a T)> {
+        directions
+            .iter()
+            .filter_map(move |&delta| self.offset(pos, delta))
+            .map(move |pos| (pos, &self[pos]))
+    }
+
+    /// Orthogonal neighbours inside the grid
+    pub fn neighbours4(&self, pos: Pos) -> impl Iterator<Item = (Pos, &T)> {
+        self.neighbours(pos, &Compass::ALL)
+    }
+
+    /// Orthogonal and diagonal neighbours inside the grid
+    pub fn neighbours8(&self, pos: Pos) -> impl Iterator<Item = (Pos, &T)> {
+        self.neighbours(pos, &Direction8::ALL)
+    }
+
+    /// Cells seen from `pos` looking along `direction`, up to the edge of the grid
+    ///
+    /// ```
+    /// # use advent2020::grid::Grid;
+    /// let grid = Grid::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6], vec![7, 8, 9]]);
+    /// let ray: Vec<_> = grid.ray((0, 0), (1, 1)).map(|(_, v)| *v).collect();
+    /// assert_eq!(ray, vec![5, 9]);
+    /// ```
+    pub fn ray(
+        &self,
+        pos: Pos,
+        direction: impl Into<Vec2<i64>>,
+    ) -> impl Iterator<Item = (Pos, &T)> {
+        let direction = direction.into();
+        std::iter::successors(self.offset(pos, direction), move |&pos| {
+            self.offset(pos, direction)
+        })
+        .map(move |pos| (pos, &self[pos]))
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &T {
+        self.get(pos)
+            .unwrap_or_else(|| panic!("{:?} outside of the grid", pos))
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        self.get_mut(pos)
+            .unwrap_or_else(|| panic!("{:?} outside of the grid", pos))
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+impl<T: Cell> FromStr for Grid<T> {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Grid::from_rows(parse::grid(s)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn example() -> Grid<u8> {
+        Grid::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6]])
+    }
+
+    #[test]
+    fn test_indexing() {
+        let mut grid = example();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(2, 0)], 3);
+        assert_eq!(grid.get((3, 0)), None);
+        assert_eq!(grid.get((0, 2)), None);
+        grid[(1, 1)] = 0;
+        assert_eq!(grid.row(1), Some(&[4, 0, 6][..]));
+        assert_eq!(grid.column(2).copied().collect::<Vec<_>>(), vec![3, 6]);
+        assert_eq!(grid.column(3).count(), 0);
+        assert_eq!(grid.offset((0, 0), (-1, 0)), None);
+        assert_eq!(grid.offset((0, 0), (2, 1)), Some((2, 1)));
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = example();
+        let mut four: Vec<u8> = grid.neighbours4((1, 0)).map(|(_, &v)| v).collect();
+        four.sort_unstable();
+        assert_eq!(four, vec![1, 3, 5]);
+        assert_eq!(grid.neighbours8((1, 0)).count(), 5);
+        assert_eq!(grid.neighbours8((0, 1)).count(), 3);
+        assert_eq!(grid.ray((0, 1), (1, 0)).count(), 2);
+        assert_eq!(grid.ray((0, 1), (0, 1)).count(), 0);
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(example().to_string(), "123\n456\n");
+    }
+}
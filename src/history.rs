@@ -0,0 +1,291 @@
+//! Log of every solved part, one JSON object per line
+//!
+//! Kept outside `target/`, unlike the [`cache`](crate::cache), so timings and
+//! answers can be followed from one revision to the next, across `cargo clean`.
+
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::PathBuf;
+use std::process::Command;
+use std::time::{Duration, SystemTime};
+
+use serde::{Deserialize, Serialize};
+
+use crate::answer::Answer;
+use crate::cache::sha256;
+use crate::Day;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Record {
+    /// Seconds since the Unix epoch
+    pub timestamp: u64,
+    /// `git describe` of the working tree, see [`revision`]
+    pub revision: Option<String>,
+    pub year: u16,
+    pub day: usize,
+    pub part: usize,
+    pub answer: Answer,
+    pub elapsed: Duration,
+    /// SHA-256 of the day's input, telling input sets apart
+    pub input: String,
+    /// Answer and time were read from the cache, not measured
+    pub cached: bool,
+}
+
+impl Record {
+    /// Both parts of a solved day, stamped now
+    pub fn from_day(year: u16, day: usize, input: &str, solved: &Day, cached: bool) -> [Self; 2] {
+        let timestamp = SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .map_or(0, |since| since.as_secs());
+        let record = |part: usize, answer: &Answer, elapsed: Duration| Record {
+            timestamp,
+            revision: revision(),
+            year,
+            day,
+            part,
+            answer: answer.clone(),
+            elapsed,
+            input: sha256(input.as_bytes()),
+            cached,
+        };
+        [
+            record(1, &solved.answers.0, solved.stats.0.elapsed),
+            record(2, &solved.answers.1, solved.stats.1.elapsed),
+        ]
+    }
+}
+
+/// Revision of the current directory's git checkout, `-dirty` when modified
+///
+/// `None` outside a checkout or without git.
+pub fn revision() -> Option<String> {
+    lazy_static! {
+        static ref REVISION: Option<String> = Command::new("git")
+            .args(["describe", "--always", "--dirty"])
+            .output()
+            .ok()
+            .filter(|output| output.status.success())
+            .and_then(|output| String::from_utf8(output.stdout).ok())
+            .map(|revision| revision.trim().to_string());
+    }
+    REVISION.clone()
+}
+
+#[derive(Debug, Clone)]
+pub struct History {
+    path: PathBuf,
+}
+
+impl History {
+    pub fn new<P: Into<PathBuf>>(path: P) -> Self {
+        History { path: path.into() }
+    }
+
+    pub fn append(&self, records: &[Record]) -> io::Result<()> {
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let mut lines = String::new();
+        for record in records {
+            lines += &serde_json::to_string(record)?;
+            lines.push('\n');
+        }
+        // One write, so runs in parallel don't interleave their lines
+        OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)?
+            .write_all(lines.as_bytes())
+    }
+
+    /// Every record in the order written, a missing file is an empty history
+    ///
+    /// Lines that don't parse, like one cut short by a crash, are skipped.
+    pub fn read(&self) -> io::Result<Vec<Record>> {
+        match fs::read_to_string(&self.path) {
+            Ok(history) => Ok(history
+                .lines()
+                .filter_map(|line| serde_json::from_str(line).ok())
+                .collect()),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Vec::new()),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+/// Measured runs of a part under one revision
+#[derive(Debug, PartialEq)]
+pub struct Timing<'a> {
+    pub revision: Option<&'a str>,
+    pub runs: usize,
+    pub median: Duration,
+}
+
+/// Median time per revision, in the order revisions were first run
+///
+/// Cached records are left out, their time is that of an earlier run.
+pub fn timings<'a>(records: &[&'a Record]) -> Vec<Timing<'a>> {
+    let mut revisions: Vec<(Option<&str>, Vec<Duration>)> = Vec::new();
+    for record in records.iter().filter(|record| !record.cached) {
+        let revision = record.revision.as_deref();
+        match revisions.iter_mut().find(|(seen, _)| *seen == revision) {
+            Some((_, elapsed)) => elapsed.push(record.elapsed),
+            None => revisions.push((revision, vec![record.elapsed])),
+        }
+    }
+    revisions
+        .into_iter()
+        .map(|(revision, mut elapsed)| {
+            elapsed.sort_unstable();
+            Timing {
+                revision,
+                runs: elapsed.len(),
+                median: elapsed[elapsed.len() / 2],
+            }
+        })
+        .collect()
+}
+
+/// The first answer on each input, then every run that answered differently
+pub fn answer_changes<'a>(records: &[&'a Record]) -> Vec<(Option<&'a Answer>, &'a Record)> {
+    let mut last: Vec<(&str, &Answer)> = Vec::new();
+    let mut changes = Vec::new();
+    for &record in records {
+        match last.iter_mut().find(|(input, _)| *input == record.input) {
+            Some((_, answer)) if *answer == &record.answer => (),
+            Some((_, answer)) => {
+                changes.push((Some(*answer), record));
+                *answer = &record.answer;
+            }
+            None => {
+                changes.push((None, record));
+                last.push((&record.input, &record.answer));
+            }
+        }
+    }
+    changes
+}
+
+/// `YYYY-MM-DD HH:MM` in UTC
+///
+/// ```
+/// # use advent2020::history::format_timestamp;
+/// assert_eq!(format_timestamp(0), "1970-01-01 00:00");
+/// assert_eq!(format_timestamp(1_608_854_400 + 90), "2020-12-25 00:01");
+/// ```
+pub fn format_timestamp(timestamp: u64) -> String {
+    let (days, seconds) = (timestamp / 86_400, timestamp % 86_400);
+    // Civil date from days since 1970-01-01, in 400 year eras starting in March
+    let days = days + 719_468;
+    let era = days / 146_097;
+    let day_of_era = days % 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_from_march = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_from_march + 2) / 5 + 1;
+    let month = if month_from_march < 10 {
+        month_from_march + 3
+    } else {
+        month_from_march - 9
+    };
+    let year = era * 400 + year_of_era + u64::from(month <= 2);
+    format!(
+        "{:04}-{:02}-{:02} {:02}:{:02}",
+        year,
+        month,
+        day,
+        seconds / 3_600,
+        seconds % 3_600 / 60
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(revision: &str, input: &str, answer: i64, millis: u64, cached: bool) -> Record {
+        Record {
+            timestamp: 1_608_854_400,
+            revision: Some(revision.to_string()),
+            year: 2020,
+            day: 7,
+            part: 1,
+            answer: Answer::Int(answer),
+            elapsed: Duration::from_millis(millis),
+            input: input.to_string(),
+            cached,
+        }
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let dir = std::env::temp_dir().join(format!("advent-history-test-{}", std::process::id()));
+        let history = History::new(dir.join("history.jsonl"));
+        assert_eq!(history.read().unwrap(), []);
+        let records = [record("abc", "in", 246, 7, false)];
+        history.append(&records).unwrap();
+        fs::write(
+            dir.join("history.jsonl"),
+            fs::read_to_string(dir.join("history.jsonl")).unwrap() + "{\"timestamp\":",
+        )
+        .unwrap();
+        history.append(&records).unwrap();
+        assert_eq!(history.read().unwrap(), [records[0].clone()]);
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_timings() {
+        let records = [
+            record("abc", "in", 246, 7, false),
+            record("abc", "in", 246, 9, false),
+            record("abc", "in", 246, 8, false),
+            record("def", "in", 246, 1, true),
+            record("def", "in", 246, 12, false),
+        ];
+        let records: Vec<&Record> = records.iter().collect();
+        assert_eq!(
+            timings(&records),
+            [
+                Timing {
+                    revision: Some("abc"),
+                    runs: 3,
+                    median: Duration::from_millis(8)
+                },
+                Timing {
+                    revision: Some("def"),
+                    runs: 1,
+                    median: Duration::from_millis(12)
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_answer_changes() {
+        let records = [
+            record("abc", "in", 246, 7, false),
+            record("abc", "other", 112, 7, false),
+            record("def", "in", 246, 7, false),
+            record("def", "in", 247, 7, false),
+            record("ghi", "other", 112, 7, false),
+            record("ghi", "in", 246, 7, true),
+        ];
+        let records: Vec<&Record> = records.iter().collect();
+        let changes: Vec<_> = answer_changes(&records)
+            .into_iter()
+            .map(|(before, record)| (before.cloned(), record.answer.clone()))
+            .collect();
+        assert_eq!(
+            changes,
+            [
+                (None, Answer::Int(246)),
+                (None, Answer::Int(112)),
+                (Some(Answer::Int(246)), Answer::Int(247)),
+                (Some(Answer::Int(247)), Answer::Int(246)),
+            ]
+        );
+    }
+}
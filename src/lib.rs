@@ -1,59 +1,175 @@
+// The `Recap` derive expands to a non-local `FromStr` impl wrapped in `Ok(...?)`
+#![allow(non_local_definitions, clippy::needless_question_mark)]
+
 #[macro_use]
 extern crate lazy_static;
 
+use std::fmt;
 use std::format;
 use std::fs;
 
-pub mod day1;
-pub mod day10;
-pub mod day11;
-pub mod day12;
-pub mod day13;
-pub mod day14;
-pub mod day15;
-pub mod day16;
-pub mod day17;
-pub mod day2;
-pub mod day3;
-pub mod day4;
-pub mod day5;
-pub mod day6;
-pub mod day7;
-pub mod day8;
-pub mod day9;
+use answer::Answer;
+use config::Config;
+
+pub mod answer;
+pub mod automaton;
+pub mod cache;
+pub mod config;
+pub mod external;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+pub mod geom;
+pub mod graph;
+pub mod grid;
+pub mod history;
+pub mod memory;
+pub mod meta;
+pub mod numtheory;
+pub mod parse;
+pub mod repl;
+pub mod trace;
+pub mod watch;
+pub mod y2020;
 
 #[derive(Debug, Default)]
-pub struct Parts(pub String, pub String);
+pub struct Parts<T = String>(pub T, pub T);
+
+/// One of the two parts of a day
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Part {
+    One,
+    Two,
+}
 
 #[derive(Debug, Default)]
 pub struct Day {
-    pub answers: Parts,
+    pub answers: Parts<Answer>,
     pub display: Parts,
     pub visual: Option<String>,
+    /// How each part's answer was reached, in order
+    pub steps: Parts<Vec<String>>,
+    /// Time and allocations per part, parsing is counted in part 1
+    pub stats: Parts<memory::Stats>,
+}
+
+/// Typed answer of a single part, along with its human-readable form
+#[derive(Debug, Clone)]
+pub struct Solution<T> {
+    pub answer: T,
+    pub display: String,
+    /// Reasoning steps leading to the answer, in order
+    pub steps: Vec<String>,
+}
+
+impl<T> Solution<T> {
+    pub fn new(answer: T, display: String) -> Self {
+        Solution {
+            answer,
+            display,
+            steps: Vec::new(),
+        }
+    }
+
+    pub fn with_steps(self, steps: Vec<String>) -> Self {
+        Solution { steps, ..self }
+    }
+}
+
+/// Why a day could not be solved
+#[derive(Debug)]
+pub enum SolveError {
+    Parse(parse::ParseError),
+    Config(config::ConfigError),
+}
+
+impl SolveError {
+    /// A parse error points into `input`, read from `file`
+    pub fn render(&self, file: &str, input: &str) -> String {
+        match self {
+            Self::Parse(e) => e.render(file, input),
+            Self::Config(e) => format!("error: {}", e),
+        }
+    }
+}
+
+impl fmt::Display for SolveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Parse(e) => write!(f, "{}", e),
+            Self::Config(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl std::error::Error for SolveError {}
+
+impl From<parse::ParseError> for SolveError {
+    fn from(e: parse::ParseError) -> Self {
+        Self::Parse(e)
+    }
+}
+
+impl From<config::ConfigError> for SolveError {
+    fn from(e: config::ConfigError) -> Self {
+        Self::Config(e)
+    }
+}
+
+impl Day {
+    pub fn new<A: Into<Answer>, B: Into<Answer>>(part1: Solution<A>, part2: Solution<B>) -> Day {
+        Day {
+            answers: Parts(part1.answer.into(), part2.answer.into()),
+            display: Parts(part1.display, part2.display),
+            steps: Parts(part1.steps, part2.steps),
+            ..Default::default()
+        }
+    }
+}
+
+/// Solver of one day
+#[derive(Clone, Copy)]
+pub struct Puzzle {
+    pub day: usize,
+    /// Solves the input file
+    pub main: fn(&Config) -> Result<Day, SolveError>,
+    pub solve: fn(&str, &Config) -> Result<Day, SolveError>,
+    /// Solves a single part of the input, for callers that need one answer
+    pub part: fn(&str, &Config, Part) -> Result<Answer, SolveError>,
+    /// Input file `main` reads from the year's inputs
+    pub input: &'static str,
+    pub meta: &'static meta::Meta,
+    /// Parses the input without solving, for reporting malformed input
+    pub check: fn(&str) -> Result<(), parse::ParseError>,
+    /// Checks the day's parameters, for reporting a bad config before solving
+    pub params: fn(&Config) -> Result<(), config::ConfigError>,
+    /// Interactive session over the parsed input, for days that have one
+    pub repl: Option<repl::Open>,
+}
+
+/// One Advent of Code event
+pub struct Year {
+    pub year: u16,
+    pub days: Vec<Puzzle>,
+}
+
+impl Year {
+    pub fn day(&self, day: usize) -> Option<&Puzzle> {
+        self.days.iter().find(|puzzle| puzzle.day == day)
+    }
+}
+
+pub fn get_years() -> Vec<Year> {
+    vec![Year {
+        year: y2020::YEAR,
+        days: y2020::get_days(),
+    }]
+}
+
+pub fn get_year(year: u16) -> Option<Year> {
+    get_years().into_iter().find(|y| y.year == year)
 }
 
-pub fn get_days() -> Vec<fn() -> Day> {
-    vec![
-        day1::main,
-        day2::main,
-        day3::main,
-        day4::main,
-        day5::main,
-        day6::main,
-        day7::main,
-        day8::main,
-        day9::main,
-        day10::main,
-        day11::main,
-        day12::main,
-        day13::main,
-        day14::main,
-        day15::main,
-        day16::main,
-        day17::main,
-    ]
-}
-
-pub fn get_string(name: &str) -> String {
-    fs::read_to_string(format!("inputs/{}", name)).expect("Something went wrong reading the file")
+pub fn get_input(year: u16, name: &str) -> String {
+    fs::read_to_string(format!("inputs/{}/{}", year, name))
+        .expect("Something went wrong reading the file")
 }
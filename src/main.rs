@@ -1,8 +1,26 @@
+use std::fs;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
 use colored::*;
 use structopt::StructOpt;
 
-use advent2020::get_days;
-use advent2020::Day;
+use advent2020::cache::{self, Cache, Key};
+use advent2020::config::Config;
+use advent2020::external::{self, Request, Verdict};
+use advent2020::history::{self, History, Record};
+use advent2020::memory;
+use advent2020::meta::{self, Tag};
+use advent2020::repl;
+use advent2020::trace;
+use advent2020::watch::Watcher;
+use advent2020::{get_year, get_years, Day, Puzzle, SolveError, Year};
+
+const DEFAULT_CONFIG: &str = "advent.toml";
+const DEFAULT_CACHE_DIR: &str = "target/advent-cache";
+const DEFAULT_HISTORY: &str = "advent-history.jsonl";
+const WATCH_INTERVAL: Duration = Duration::from_millis(200);
 
 #[derive(StructOpt)]
 struct Cli {
@@ -13,69 +31,700 @@ struct Cli {
 
     #[structopt(long)]
     parallel: bool,
+
+    /// Event to run, defaults to the latest one
+    #[structopt(long)]
+    year: Option<u16>,
+
+    /// Puzzle parameters file with `[<year>.<day>]` tables, `advent.toml` is used when present
+    #[structopt(long, parse(from_os_str))]
+    config: Option<PathBuf>,
+
+    /// Override a puzzle parameter, e.g. `--param day15.part2_turn=2020`
+    #[structopt(long = "param", number_of_values = 1)]
+    params: Vec<String>,
+
+    /// Trace a solver to stderr, e.g. `--trace day11=debug`, or every solver with `all=<level>`
+    #[structopt(long = "trace", number_of_values = 1)]
+    traces: Vec<String>,
+
+    /// Solve everything again, without reading or writing cached results
+    #[structopt(long)]
+    no_cache: bool,
+
+    /// JSON-lines log of every solved part, defaults to `advent-history.jsonl`
+    #[structopt(long, parse(from_os_str))]
+    history_file: Option<PathBuf>,
+
+    /// Solve without adding the answers and timings to the history
+    #[structopt(long)]
+    no_history: bool,
+
+    /// Input-set directory, defaults to `inputs/<year>`
+    #[structopt(long, parse(from_os_str))]
+    inputs: Option<PathBuf>,
+
+    /// Solve again whenever the day's input file, or with `--all` the input set, changes
+    #[structopt(long)]
+    watch: bool,
+
+    /// Show each day's visual in every run mode, not only for a puzzle chosen by number
+    #[structopt(long)]
+    visual: bool,
+
+    /// Write visuals to `<dir>/day<N>.txt` instead of printing them, implies `--visual`
+    #[structopt(long, parse(from_os_str))]
+    visual_dir: Option<PathBuf>,
+
+    /// List the reasoning steps behind each answer
+    #[structopt(long)]
+    explain: bool,
+
+    #[structopt(subcommand)]
+    command: Option<Command>,
 }
 
-fn print_day<F>(day: usize, calc: F)
-where
-    F: FnOnce() -> Day,
-{
-    println!("Day {}", day);
-    let day = calc();
-    println!("Part 1: {}", day.display.0);
-    println!("Part 2: {}", day.display.1);
-    println!();
+#[derive(StructOpt)]
+enum Command {
+    /// Parse a day's input once, then query it interactively
+    Repl { day: usize },
+    /// List the days with their topics, e.g. `list --tag grid`
+    List {
+        /// Only days with every given tag: grid, graph, number-theory or vm
+        #[structopt(long = "tag", number_of_values = 1)]
+        tags: Vec<Tag>,
+    },
+    /// Diff another solver's answers against ours, per day and input set
+    Compare {
+        /// Solver command, run with the input on stdin and a JSON request as its last argument
+        #[structopt(long)]
+        external: String,
+        /// Input-set directory to compare on, repeatable, defaults to `--inputs`
+        #[structopt(long = "set", parse(from_os_str), number_of_values = 1)]
+        sets: Vec<PathBuf>,
+        /// Days to compare, defaults to all
+        days: Vec<usize>,
+    },
+    /// Timings per revision and answer changes from the run history
+    History {
+        /// Days to show, defaults to all
+        days: Vec<usize>,
+    },
+}
+
+/// The year's config with `--param` overrides, every day's parameters checked
+fn load_config(args: &Cli, year: &Year) -> Config {
+    let config = match &args.config {
+        Some(path) => Config::from_file(path).unwrap_or_else(|e| panic!("{}", e)),
+        None if Path::new(DEFAULT_CONFIG).exists() => {
+            Config::from_file(DEFAULT_CONFIG).unwrap_or_else(|e| panic!("{}", e))
+        }
+        None => Config::default(),
+    };
+    let mut config = config.year(year.year).unwrap_or_else(|e| panic!("{}", e));
+    for param in &args.params {
+        config.set(param).unwrap_or_else(|e| panic!("{}", e));
+    }
+    let days: Vec<String> = year
+        .days
+        .iter()
+        .map(|puzzle| format!("day{}", puzzle.day))
+        .collect();
+    config.only_days(&days).unwrap_or_else(|e| panic!("{}", e));
+    for puzzle in &year.days {
+        (puzzle.params)(&config).unwrap_or_else(|e| panic!("{}", e));
+    }
+    config
+}
+
+/// A day's results, and whether they came from the cache
+struct Solved {
+    day: Day,
+    cached: bool,
+}
+
+/// Everything solving a day depends on besides the puzzle
+#[derive(Clone)]
+struct Runner {
+    year: u16,
+    inputs: PathBuf,
+    config: Config,
+    cache: Option<Cache>,
+    history: Option<History>,
+}
+
+impl Runner {
+    fn input_path(&self, puzzle: &Puzzle) -> PathBuf {
+        self.inputs.join(puzzle.input)
+    }
+
+    /// Solves the day's input file, or renders why it could not be parsed
+    fn solve(&self, puzzle: &Puzzle) -> Result<Solved, String> {
+        let path = self.input_path(puzzle);
+        let input = fs::read_to_string(&path)
+            .unwrap_or_else(|e| panic!("could not read {}: {}", path.display(), e));
+        self.solve_input(puzzle, &input)
+            .map_err(|e| e.render(&path.display().to_string(), &input))
+    }
+
+    /// Solves `puzzle` and logs it to the history
+    fn solve_input(&self, puzzle: &Puzzle, input: &str) -> Result<Solved, SolveError> {
+        let solved = self.solve_cached(puzzle, input)?;
+        if let Some(history) = &self.history {
+            let records =
+                Record::from_day(self.year, puzzle.day, input, &solved.day, solved.cached);
+            if let Err(e) = history.append(&records) {
+                eprintln!(
+                    "{}",
+                    format!("Could not log day {} to the history: {}", puzzle.day, e).yellow()
+                );
+            }
+        }
+        Ok(solved)
+    }
+
+    /// Solves `puzzle`, or reuses the results of an identical earlier run
+    fn solve_cached(&self, puzzle: &Puzzle, input: &str) -> Result<Solved, SolveError> {
+        let cache = match &self.cache {
+            Some(cache) => cache,
+            None => {
+                return Ok(Solved {
+                    day: (puzzle.solve)(input, &self.config)?,
+                    cached: false,
+                });
+            }
+        };
+        let params = self.config.overrides(&format!("day{}", puzzle.day));
+        let build = cache::build_fingerprint();
+        let key = Key {
+            year: self.year,
+            day: puzzle.day,
+            input,
+            params: &params,
+            build: &build,
+        };
+        if let Some(day) = cache.load(&key) {
+            return Ok(Solved { day, cached: true });
+        }
+        // Only input that parsed is ever cached
+        let day = (puzzle.solve)(input, &self.config)?;
+        if let Err(e) = cache.store(&key, &day) {
+            eprintln!(
+                "{}",
+                format!("Could not cache day {}: {}", puzzle.day, e).yellow()
+            );
+        }
+        Ok(Solved { day, cached: false })
+    }
+}
+
+/// What becomes of the visuals of solved days
+enum Visuals {
+    Hidden,
+    Printed,
+    /// Written to `day<N>.txt` in the directory
+    Written(PathBuf),
+}
+
+impl Visuals {
+    fn show(&self, puzzle: &Puzzle, visual: &str) {
+        match self {
+            Self::Hidden => (),
+            Self::Printed => {
+                println!();
+                println!("{}", visual);
+                println!();
+            }
+            Self::Written(dir) => {
+                let path = dir.join(format!("day{}.txt", puzzle.day));
+                match fs::create_dir_all(dir).and_then(|_| fs::write(&path, visual)) {
+                    Ok(()) => println!(
+                        "{}",
+                        format!("Visual written to {}", path.display()).dimmed()
+                    ),
+                    Err(e) => eprintln!(
+                        "{}",
+                        format!("Could not write {}: {}", path.display(), e).yellow()
+                    ),
+                }
+            }
+        }
+    }
+}
+
+/// What to show of each day besides its answers
+struct Show {
+    visuals: Visuals,
+    /// Reasoning steps under each part
+    explain: bool,
 }
 
-fn print_day_visual<F>(day: usize, calc: F)
+/// Prints both parts, marking what changed since `previous` when there is one
+fn print_parts(solved: &Solved, previous: Option<&Solved>, explain: bool) {
+    let Solved { day, cached } = solved;
+    let parts = [
+        (&day.display.0, &day.answers.0, &day.stats.0, &day.steps.0),
+        (&day.display.1, &day.answers.1, &day.stats.1, &day.steps.1),
+    ];
+    for (n, (display, answer, stats, steps)) in parts.iter().enumerate() {
+        let before = previous.map(|previous| {
+            let day = &previous.day;
+            if n == 0 {
+                (&day.answers.0, &day.stats.0)
+            } else {
+                (&day.answers.1, &day.stats.1)
+            }
+        });
+        let mut note = format!("{:.1?}", stats.elapsed);
+        if let Some((_, before)) = before {
+            note += &format!(", was {:.1?}", before.elapsed);
+        }
+        if *cached {
+            note += ", cached";
+        }
+        match before {
+            Some((old, _)) if old != *answer => println!(
+                "Part {}: {} {} {}",
+                n + 1,
+                display.yellow().bold(),
+                format!("(was {})", old).yellow(),
+                format!("({})", note).dimmed()
+            ),
+            _ => println!(
+                "Part {}: {} {}",
+                n + 1,
+                display,
+                format!("({})", note).dimmed()
+            ),
+        }
+        if explain {
+            for (i, step) in steps.iter().enumerate() {
+                println!("{} {}", format!("{:>4}.", i + 1).dimmed(), step);
+            }
+        }
+    }
+    // Allocations are not cached
+    if memory::ENABLED && !cached {
+        println!("{}", format!("Part 1 memory: {}", day.stats.0).dimmed());
+        println!("{}", format!("Part 2 memory: {}", day.stats.1).dimmed());
+    }
+}
+
+/// Day and title, with its tags, and its link and complexity when `details` is set
+fn print_header(year: u16, puzzle: &Puzzle, details: bool) {
+    let meta = puzzle.meta;
+    let tags = if meta.tags.is_empty() {
+        String::new()
+    } else {
+        format!(" [{}]", meta.tag_names())
+    };
+    println!("Day {}: {}{}", puzzle.day, meta.title, tags.dimmed());
+    if details {
+        println!("{}", meta::url(year, puzzle.day).dimmed());
+        println!("{}", format!("Expected {}", meta.complexity).dimmed());
+    }
+}
+
+fn print_list(year: u16, puzzles: &[Puzzle], tags: &[Tag]) {
+    for puzzle in puzzles {
+        let meta = puzzle.meta;
+        if !tags.iter().all(|&tag| meta.has_tag(tag)) {
+            continue;
+        }
+        println!(
+            "{:>2}  {:<24} {:<16} {}",
+            puzzle.day,
+            meta.title,
+            meta.tag_names(),
+            meta.complexity
+        );
+        println!("    {}", meta::url(year, puzzle.day).dimmed());
+        for strategy in meta.strategies {
+            println!("    {}", format!("- {}", strategy).dimmed());
+        }
+    }
+}
+
+/// Header, visual and parts of a day, with the header's details for a chosen puzzle
+///
+/// Returns whether the day could be solved.
+fn print_day<F>(year: u16, puzzle: &Puzzle, details: bool, show: &Show, calc: F) -> bool
 where
-    F: FnOnce() -> Day,
+    F: FnOnce() -> Result<Solved, String>,
 {
-    println!("Day {}", day);
-    let day = calc();
+    print_header(year, puzzle, details);
+    // A day that panics fails alone, `--all` goes on with the next
+    let solved = match panic::catch_unwind(AssertUnwindSafe(calc)) {
+        Ok(Ok(solved)) => solved,
+        Ok(Err(diagnostic)) => {
+            eprintln!("{}", diagnostic);
+            return false;
+        }
+        Err(_) => {
+            println!("{}", "Failed, see the error above".red());
+            println!();
+            return false;
+        }
+    };
+    if let Some(visual) = &solved.day.visual {
+        show.visuals.show(puzzle, visual);
+    }
+    print_parts(&solved, None, show.explain);
     println!();
-    if let Some(s) = day.visual {
-        println!("{}", s);
+    true
+}
+
+/// Solves `puzzles` on every change to `paths`, never returns
+fn watch(runner: &Runner, puzzles: &[Puzzle], show: &Show, paths: Vec<PathBuf>) -> ! {
+    let mut watcher = Watcher::new(paths, WATCH_INTERVAL);
+    let mut previous: Vec<Option<Solved>> = puzzles.iter().map(|_| None).collect();
+    loop {
+        print!("\x1b[2J\x1b[H");
+        println!(
+            "{}",
+            format!("Advent Of Code {}", runner.year).bold().blue()
+        );
         println!();
+        for (puzzle, previous) in puzzles.iter().zip(previous.iter_mut()) {
+            print_header(runner.year, puzzle, false);
+            // An input being edited is often malformed, keep watching
+            match panic::catch_unwind(AssertUnwindSafe(|| runner.solve(puzzle))) {
+                Ok(Err(diagnostic)) => eprintln!("{}", diagnostic),
+                Ok(Ok(solved)) => {
+                    if let Some(visual) = &solved.day.visual {
+                        show.visuals.show(puzzle, visual);
+                    }
+                    print_parts(&solved, previous.as_ref(), show.explain);
+                    *previous = Some(solved);
+                }
+                Err(_) => println!("{}", "Failed, see the error above".red()),
+            }
+            println!();
+        }
+        println!("{}", "Watching for changes, Ctrl-C to stop".dimmed());
+        watcher.wait();
     }
-    println!("Part 1: {}", day.display.0);
-    println!("Part 2: {}", day.display.1);
-    println!();
+}
+
+fn run_repl(runner: &Runner, puzzle: &Puzzle) {
+    let open = puzzle
+        .repl
+        .unwrap_or_else(|| panic!("day {} has no repl", puzzle.day));
+    let path = runner.input_path(puzzle);
+    let input = fs::read_to_string(&path)
+        .unwrap_or_else(|e| panic!("could not read {}: {}", path.display(), e));
+    if let Err(e) = (puzzle.check)(&input) {
+        eprintln!("{}", e.render(&path.display().to_string(), &input));
+        std::process::exit(1);
+    }
+    let mut session = open(&input, &runner.config);
+    print_header(runner.year, puzzle, false);
+    println!("{}", "`help` lists the commands".dimmed());
+    let prompt = format!("day{}> ", puzzle.day);
+    let stdin = std::io::stdin();
+    repl::run(session.as_mut(), &prompt, stdin.lock(), std::io::stdout()).unwrap();
+}
+
+/// Each part's median time per revision, and the runs that changed its answer
+fn print_history(year: u16, puzzles: &[&Puzzle], records: &[Record]) {
+    for puzzle in puzzles {
+        print_header(year, puzzle, false);
+        let mut empty = true;
+        for part in 1..=2 {
+            let records: Vec<&Record> = records
+                .iter()
+                .filter(|r| (r.year, r.day, r.part) == (year, puzzle.day, part))
+                .collect();
+            if records.is_empty() {
+                continue;
+            }
+            empty = false;
+            println!("Part {}: {} runs", part, records.len());
+            let revision = |revision: Option<&str>| revision.unwrap_or("no revision").to_string();
+            let mut previous: Option<Duration> = None;
+            for timing in history::timings(&records) {
+                let mut line = format!(
+                    "  {:<24} {:>4} measured, median {:>9}",
+                    revision(timing.revision),
+                    timing.runs,
+                    format!("{:.1?}", timing.median)
+                );
+                // Slowdowns and speedups over 10% since the revision before stand out
+                if let Some(previous) = previous {
+                    let ratio = timing.median.as_secs_f64() / previous.as_secs_f64();
+                    let change = format!("{:+.0}%", (ratio - 1.0) * 100.0);
+                    let change = match ratio {
+                        r if r > 1.1 => change.red(),
+                        r if r < 0.9 => change.green(),
+                        _ => change.dimmed(),
+                    };
+                    line += &format!(" {}", change);
+                }
+                println!("{}", line);
+                previous = Some(timing.median);
+            }
+            for (before, record) in history::answer_changes(&records) {
+                let when = format!(
+                    "  {} {:<24}",
+                    history::format_timestamp(record.timestamp),
+                    revision(record.revision.as_deref())
+                );
+                match before {
+                    None => println!("{} {}", when.dimmed(), record.answer),
+                    Some(before) => println!(
+                        "{} {} {}",
+                        when.dimmed(),
+                        record.answer.to_string().yellow().bold(),
+                        format!("(was {})", before).yellow()
+                    ),
+                }
+            }
+        }
+        if empty {
+            println!("{}", "No runs yet".dimmed());
+        }
+        println!();
+    }
+}
+
+/// Runs `command` on every day with an input in each set, returns whether all answers agree
+fn compare(runner: &Runner, puzzles: &[&Puzzle], command: &str, sets: &[PathBuf]) -> bool {
+    // Other input sets would show up in the history as answer changes
+    let runner = &Runner {
+        history: None,
+        ..runner.clone()
+    };
+    let mut agree = true;
+    for set in sets {
+        println!("{}", format!("Input set {}", set.display()).bold());
+        println!();
+        for puzzle in puzzles {
+            print_header(runner.year, puzzle, false);
+            let path = set.join(puzzle.input);
+            let input = match fs::read_to_string(&path) {
+                Ok(input) => input,
+                Err(_) => {
+                    println!("{}", format!("No {} in this set", puzzle.input).dimmed());
+                    println!();
+                    continue;
+                }
+            };
+            let request = Request {
+                year: runner.year,
+                day: puzzle.day,
+                params: runner.config.table(&format!("day{}", puzzle.day)),
+            };
+            let theirs = external::run(command, &request, &input);
+            let day = match runner.solve_input(puzzle, &input) {
+                Ok(Solved { day, .. }) => day,
+                Err(e) => {
+                    agree = false;
+                    eprintln!("{}", e.render(&path.display().to_string(), &input));
+                    continue;
+                }
+            };
+            let theirs = match theirs {
+                Ok(theirs) => theirs,
+                Err(e) => {
+                    agree = false;
+                    println!("{}", e.to_string().red());
+                    println!();
+                    continue;
+                }
+            };
+            let parts = [
+                (&day.answers.0, &theirs.answers.0),
+                (&day.answers.1, &theirs.answers.1),
+            ];
+            for (n, (ours, theirs)) in parts.iter().enumerate() {
+                let verdict = match Verdict::new(ours, theirs) {
+                    Verdict::Same => "same".green(),
+                    Verdict::Differs(theirs) => {
+                        agree = false;
+                        format!("differs, theirs is {}", theirs).red().bold()
+                    }
+                    Verdict::Unanswered => "unanswered".yellow(),
+                };
+                println!("Part {}: {} {}", n + 1, ours, verdict);
+            }
+            let elapsed = day.stats.0.elapsed + day.stats.1.elapsed;
+            println!(
+                "{}",
+                format!("Ours {:.1?}, theirs {:.1?}", elapsed, theirs.elapsed).dimmed()
+            );
+            println!();
+        }
+    }
+    agree
 }
 
 fn main() {
-    println!("{}", "Advent Of Code 2020".bold().blue());
+    let args = Cli::from_args();
+    let year = match args.year {
+        Some(year) => get_year(year).expect("invalid year"),
+        None => get_years().pop().unwrap(),
+    };
+    let config = load_config(&args, &year);
+    trace::init(year.year, &args.traces).unwrap_or_else(|e| panic!("{}", e));
+    // Cached days would not emit any trace
+    let cache = if args.no_cache || !args.traces.is_empty() {
+        None
+    } else {
+        Some(Cache::new(DEFAULT_CACHE_DIR))
+    };
+    let history_file = args
+        .history_file
+        .clone()
+        .unwrap_or_else(|| PathBuf::from(DEFAULT_HISTORY));
+    // Traced runs are slowed down by the tracing itself
+    let history = if args.no_history || !args.traces.is_empty() {
+        None
+    } else {
+        Some(History::new(&history_file))
+    };
+    let runner = Runner {
+        year: year.year,
+        inputs: args
+            .inputs
+            .clone()
+            .unwrap_or_else(|| Path::new("inputs").join(year.year.to_string())),
+        config,
+        cache,
+        history,
+    };
+
+    let show = Show {
+        visuals: match (&args.visual_dir, args.visual) {
+            (Some(dir), _) => Visuals::Written(dir.clone()),
+            (None, true) => Visuals::Printed,
+            (None, false) => Visuals::Hidden,
+        },
+        explain: args.explain,
+    };
+
+    match &args.command {
+        Some(Command::Repl { day }) => {
+            run_repl(&runner, year.day(*day).expect("invalid day"));
+            return;
+        }
+        Some(Command::List { tags }) => {
+            println!("{}", format!("Advent Of Code {}", year.year).bold().blue());
+            println!();
+            print_list(year.year, &year.days, tags);
+            return;
+        }
+        Some(Command::Compare {
+            external,
+            sets,
+            days,
+        }) => {
+            println!("{}", format!("Advent Of Code {}", year.year).bold().blue());
+            println!();
+            let puzzles: Vec<&Puzzle> = if days.is_empty() {
+                year.days.iter().collect()
+            } else {
+                days.iter()
+                    .map(|&day| year.day(day).expect("invalid day"))
+                    .collect()
+            };
+            let sets = if sets.is_empty() {
+                vec![runner.inputs.clone()]
+            } else {
+                sets.clone()
+            };
+            if !compare(&runner, &puzzles, external, &sets) {
+                std::process::exit(1);
+            }
+            return;
+        }
+        Some(Command::History { days }) => {
+            println!("{}", format!("Advent Of Code {}", year.year).bold().blue());
+            println!();
+            let puzzles: Vec<&Puzzle> = if days.is_empty() {
+                year.days.iter().collect()
+            } else {
+                days.iter()
+                    .map(|&day| year.day(day).expect("invalid day"))
+                    .collect()
+            };
+            let records = History::new(&history_file)
+                .read()
+                .unwrap_or_else(|e| panic!("could not read {}: {}", history_file.display(), e));
+            print_history(year.year, &puzzles, &records);
+            return;
+        }
+        None => (),
+    }
+
+    if args.watch {
+        if args.all || args.parallel {
+            watch(&runner, &year.days, &show, vec![runner.inputs.clone()]);
+        }
+        let puzzle = match args.puzzle {
+            None => year.days.last().unwrap(),
+            Some(n) => year.day(n).expect("invalid day"),
+        };
+        watch(&runner, &[*puzzle], &show, vec![runner.input_path(puzzle)]);
+    }
+
+    println!("{}", format!("Advent Of Code {}", year.year).bold().blue());
     println!();
 
-    let args = Cli::from_args();
+    let mut solved = true;
 
     if args.all {
-        for (idx, day) in get_days().into_iter().enumerate() {
-            print_day(idx + 1, day);
+        for puzzle in &year.days {
+            solved &= print_day(year.year, puzzle, false, &show, || runner.solve(puzzle));
         }
     }
 
     if args.parallel {
-        let threads: Vec<_> = get_days()
-            .into_iter()
-            .enumerate()
-            .map(|(idx, calc)| {
-                println!("Spawn day {}", idx + 1);
-                std::thread::spawn(calc)
+        // Days solved side by side slow each other down, keep them out of the timings
+        let runner = Runner {
+            history: None,
+            ..runner.clone()
+        };
+        let threads: Vec<_> = year
+            .days
+            .iter()
+            .copied()
+            .map(|puzzle| {
+                println!("Spawn day {}", puzzle.day);
+                let runner = runner.clone();
+                let thread = std::thread::spawn(move || runner.solve(&puzzle));
+                (puzzle, thread)
             })
             .collect();
         std::thread::yield_now();
         std::thread::sleep(std::time::Duration::from_millis(50));
         println!();
-        for (idx, thread) in threads.into_iter().enumerate() {
-            print_day(idx + 1, || thread.join().unwrap());
+        for (puzzle, thread) in threads {
+            solved &= print_day(year.year, &puzzle, false, &show, || thread.join().unwrap());
         }
     }
 
     if !(args.all || args.parallel) {
-        let days = get_days();
-        match args.puzzle {
-            None => print_day(days.len(), *days.last().unwrap()),
-            Some(n) => print_day_visual(n, *days.get(n - 1).expect("invalid day")),
-        }
+        solved = match args.puzzle {
+            None => {
+                let puzzle = year.days.last().unwrap();
+                print_day(year.year, puzzle, false, &show, || runner.solve(puzzle))
+            }
+            Some(n) => {
+                let puzzle = year.day(n).expect("invalid day");
+                // A puzzle chosen by number always shows its visual
+                let show = match show.visuals {
+                    Visuals::Hidden => Show {
+                        visuals: Visuals::Printed,
+                        ..show
+                    },
+                    _ => show,
+                };
+                print_day(year.year, puzzle, true, &show, || runner.solve(puzzle))
+            }
+        };
+    }
+
+    if !solved {
+        std::process::exit(1);
     }
 }
@@ -0,0 +1,274 @@
+use std::fmt;
+use std::time::{Duration, Instant};
+
+/// Whether the counting allocator is installed (`alloc-stats` feature)
+pub const ENABLED: bool = cfg!(feature = "alloc-stats");
+
+/// Time taken and allocations made on the current thread between two laps
+///
+/// Threads spawned in between count for themselves, not for this thread, so a
+/// part that spreads its work over threads (day 17 with `parallel`) shows only
+/// the allocations of the thread that waited on them.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct Stats {
+    pub elapsed: Duration,
+    pub allocations: usize,
+    /// Bytes of every allocation, plus the growth of reallocated blocks
+    ///
+    /// A reallocation is not counted in `allocations`.
+    pub bytes: usize,
+    /// Highest number of live bytes on top of those live when the lap started
+    pub peak: usize,
+}
+
+fn human_bytes(bytes: usize) -> String {
+    const UNITS: [&str; 4] = ["B", "KiB", "MiB", "GiB"];
+    let mut value = bytes as f64;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("{} {}", bytes, UNITS[unit])
+    } else {
+        format!("{:.1} {}", value, UNITS[unit])
+    }
+}
+
+impl fmt::Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} allocations, {} allocated, {} peak",
+            self.allocations,
+            human_bytes(self.bytes),
+            human_bytes(self.peak)
+        )
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+struct Counters {
+    allocations: usize,
+    bytes: usize,
+    live: isize,
+    peak: isize,
+}
+
+#[cfg(feature = "alloc-stats")]
+mod counting {
+    use super::Counters;
+    use std::alloc::{GlobalAlloc, Layout, System};
+    use std::cell::Cell;
+
+    // Counters are per thread, so `--parallel` days don't see each other.
+    thread_local! {
+        static COUNTERS: Cell<Counters> = const {
+            Cell::new(Counters {
+                allocations: 0,
+                bytes: 0,
+                live: 0,
+                peak: 0,
+            })
+        };
+    }
+
+    /// `allocated` bytes of a new block or of a block's growth, `freed` of a release or shrink
+    fn record(new_block: bool, allocated: usize, freed: usize) {
+        // Fails silently while the thread is being torn down
+        let _ = COUNTERS.try_with(|counters| {
+            let mut c = counters.get();
+            if new_block {
+                c.allocations += 1;
+            }
+            c.bytes += allocated;
+            c.live += allocated as isize - freed as isize;
+            c.peak = c.peak.max(c.live);
+            counters.set(c);
+        });
+    }
+
+    pub(super) fn snapshot() -> Counters {
+        COUNTERS.with(|c| c.get())
+    }
+
+    pub(super) fn reset_peak() {
+        COUNTERS.with(|counters| {
+            let mut c = counters.get();
+            c.peak = c.live;
+            counters.set(c);
+        });
+    }
+
+    pub struct CountingAllocator;
+
+    unsafe impl GlobalAlloc for CountingAllocator {
+        unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+            let ptr = System.alloc(layout);
+            if !ptr.is_null() {
+                record(true, layout.size(), 0);
+            }
+            ptr
+        }
+
+        unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+            let ptr = System.alloc_zeroed(layout);
+            if !ptr.is_null() {
+                record(true, layout.size(), 0);
+            }
+            ptr
+        }
+
+        unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+            System.dealloc(ptr, layout);
+            record(false, 0, layout.size());
+        }
+
+        unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+            let new_ptr = System.realloc(ptr, layout, new_size);
+            if !new_ptr.is_null() {
+                let old_size = layout.size();
+                record(
+                    false,
+                    new_size.saturating_sub(old_size),
+                    old_size.saturating_sub(new_size),
+                );
+            }
+            new_ptr
+        }
+    }
+
+    #[global_allocator]
+    static GLOBAL: CountingAllocator = CountingAllocator;
+}
+
+#[cfg(feature = "alloc-stats")]
+use counting::{reset_peak, snapshot};
+
+#[cfg(not(feature = "alloc-stats"))]
+fn snapshot() -> Counters {
+    Counters::default()
+}
+
+#[cfg(not(feature = "alloc-stats"))]
+fn reset_peak() {}
+
+/// Stopwatch measuring time and allocations on the current thread
+///
+/// Without the `alloc-stats` feature every lap has zero allocations.
+///
+/// ```
+/// # use advent2020::memory::{Lap, ENABLED};
+/// let mut lap = Lap::new();
+/// let v: Vec<u64> = (0..1024).collect();
+/// let stats = lap.lap();
+/// assert_eq!(stats.bytes >= 8 * v.len(), ENABLED);
+/// ```
+pub struct Lap {
+    start: Counters,
+    started: Instant,
+}
+
+impl Default for Lap {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Lap {
+    pub fn new() -> Lap {
+        reset_peak();
+        Lap {
+            start: snapshot(),
+            started: Instant::now(),
+        }
+    }
+
+    pub fn lap(&mut self) -> Stats {
+        let end = snapshot();
+        let now = Instant::now();
+        reset_peak();
+        let stats = Stats {
+            elapsed: now - self.started,
+            allocations: end.allocations - self.start.allocations,
+            bytes: end.bytes - self.start.bytes,
+            peak: (end.peak - self.start.live).max(0) as usize,
+        };
+        self.start = snapshot();
+        self.started = Instant::now();
+        stats
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lap() {
+        let mut lap = Lap::new();
+        let big = vec![0_u8; 1 << 20];
+        drop(big);
+        let small = vec![0_u8; 16];
+        let stats = lap.lap();
+        if ENABLED {
+            assert_eq!(stats.allocations, 2);
+            assert_eq!(stats.bytes, (1 << 20) + 16);
+            assert!(stats.peak >= 1 << 20);
+        } else {
+            assert_eq!(
+                stats,
+                Stats {
+                    elapsed: stats.elapsed,
+                    ..Stats::default()
+                }
+            );
+        }
+        drop(small);
+        let stats = lap.lap();
+        assert_eq!(stats.allocations, 0);
+        assert!(stats.peak < 1 << 20);
+    }
+
+    #[test]
+    fn test_realloc() {
+        let mut v: Vec<u8> = Vec::with_capacity(16);
+        let mut lap = Lap::new();
+        v.reserve_exact(1024);
+        let grown = lap.lap();
+        v.shrink_to(0);
+        let shrunk = lap.lap();
+        if ENABLED {
+            assert_eq!((grown.allocations, grown.bytes), (0, 1024 - 16));
+            assert!(grown.peak >= 1024 - 16);
+            assert_eq!((shrunk.allocations, shrunk.bytes), (0, 0));
+        }
+    }
+
+    #[test]
+    fn test_other_threads() {
+        let mut lap = Lap::new();
+        std::thread::spawn(|| vec![0_u8; 1 << 20].len())
+            .join()
+            .unwrap();
+        let stats = lap.lap();
+        // Only the thread's own bookkeeping, not its megabyte
+        assert!(stats.bytes < 1 << 20);
+        assert!(stats.peak < 1 << 20);
+    }
+
+    #[test]
+    fn test_display() {
+        let stats = Stats {
+            allocations: 3,
+            bytes: 512,
+            peak: 3 << 20,
+            ..Stats::default()
+        };
+        assert_eq!(
+            stats.to_string(),
+            "3 allocations, 512 B allocated, 3.0 MiB peak"
+        );
+    }
+}
@@ -0,0 +1,112 @@
+//! What each day is about, declared next to its solver as `META`
+
+use std::fmt;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Tag {
+    Grid,
+    Graph,
+    NumberTheory,
+    /// Interpreting a small instruction set
+    Vm,
+}
+
+impl Tag {
+    pub const ALL: [Tag; 4] = [Self::Grid, Self::Graph, Self::NumberTheory, Self::Vm];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Grid => "grid",
+            Self::Graph => "graph",
+            Self::NumberTheory => "number-theory",
+            Self::Vm => "vm",
+        }
+    }
+}
+
+impl fmt::Display for Tag {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct UnknownTag(String);
+
+impl fmt::Display for UnknownTag {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let names: Vec<_> = Tag::ALL.iter().map(|tag| tag.name()).collect();
+        write!(f, "expected one of {}, got `{}`", names.join("|"), self.0)
+    }
+}
+
+impl std::error::Error for UnknownTag {}
+
+impl FromStr for Tag {
+    type Err = UnknownTag;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Tag::ALL
+            .iter()
+            .copied()
+            .find(|tag| tag.name() == s.to_lowercase().replace(' ', "-"))
+            .ok_or_else(|| UnknownTag(s.to_string()))
+    }
+}
+
+#[derive(Debug)]
+pub struct Meta {
+    pub title: &'static str,
+    pub tags: &'static [Tag],
+    /// Approaches implemented, the one `solve` uses first
+    pub strategies: &'static [&'static str],
+    /// Expected running time in terms of the input
+    pub complexity: &'static str,
+}
+
+impl Meta {
+    pub fn has_tag(&self, tag: Tag) -> bool {
+        self.tags.contains(&tag)
+    }
+
+    pub fn tag_names(&self) -> String {
+        self.tags
+            .iter()
+            .map(|tag| tag.name())
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+}
+
+/// The puzzle statement on adventofcode.com
+pub fn url(year: u16, day: usize) -> String {
+    format!("https://adventofcode.com/{}/day/{}", year, day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tag() {
+        for tag in Tag::ALL.iter() {
+            assert_eq!(tag.to_string().parse(), Ok(*tag));
+        }
+        assert_eq!("Number Theory".parse(), Ok(Tag::NumberTheory));
+        assert_eq!("VM".parse(), Ok(Tag::Vm));
+        assert_eq!(
+            "maze".parse::<Tag>().unwrap_err().to_string(),
+            "expected one of grid|graph|number-theory|vm, got `maze`"
+        );
+    }
+
+    #[test]
+    fn test_registered_days() {
+        for puzzle in crate::y2020::get_days() {
+            assert!(!puzzle.meta.title.is_empty(), "day {}", puzzle.day);
+            assert!(!puzzle.meta.strategies.is_empty(), "day {}", puzzle.day);
+        }
+        assert_eq!(url(2020, 7), "https://adventofcode.com/2020/day/7");
+    }
+}
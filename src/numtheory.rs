@@ -0,0 +1,339 @@
+//! Modular arithmetic over any primitive integer
+//!
+//! Every function returns `None` instead of overflowing, moduli must be positive.
+
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::hash::Hash;
+
+/// Primitive integers, through their checked operations
+pub trait Integer: Copy + Ord + Hash + Debug {
+    const ZERO: Self;
+    const ONE: Self;
+
+    fn checked_add(self, other: Self) -> Option<Self>;
+    fn checked_sub(self, other: Self) -> Option<Self>;
+    fn checked_mul(self, other: Self) -> Option<Self>;
+    fn checked_div(self, other: Self) -> Option<Self>;
+    /// Remainder in `0..|other|`
+    fn checked_rem_euclid(self, other: Self) -> Option<Self>;
+    fn checked_abs(self) -> Option<Self>;
+}
+
+macro_rules! impl_integer {
+    ($abs:ident: $($t:ty),*) => {
+        $(
+            impl Integer for $t {
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+
+                fn checked_add(self, other: Self) -> Option<Self> {
+                    <$t>::checked_add(self, other)
+                }
+
+                fn checked_sub(self, other: Self) -> Option<Self> {
+                    <$t>::checked_sub(self, other)
+                }
+
+                fn checked_mul(self, other: Self) -> Option<Self> {
+                    <$t>::checked_mul(self, other)
+                }
+
+                fn checked_div(self, other: Self) -> Option<Self> {
+                    <$t>::checked_div(self, other)
+                }
+
+                fn checked_rem_euclid(self, other: Self) -> Option<Self> {
+                    <$t>::checked_rem_euclid(self, other)
+                }
+
+                fn checked_abs(self) -> Option<Self> {
+                    $abs(self)
+                }
+            }
+        )*
+    };
+}
+
+fn signed_abs<T: Integer>(v: T) -> Option<T> {
+    if v < T::ZERO {
+        T::ZERO.checked_sub(v)
+    } else {
+        Some(v)
+    }
+}
+
+fn unsigned_abs<T: Integer>(v: T) -> Option<T> {
+    Some(v)
+}
+
+impl_integer!(signed_abs: i8, i16, i32, i64, i128, isize);
+impl_integer!(unsigned_abs: u8, u16, u32, u64, u128, usize);
+
+/// Greatest common divisor, never negative
+///
+/// ```
+/// # use advent2020::numtheory::gcd;
+/// assert_eq!(gcd(12, 42), Some(6));
+/// assert_eq!(gcd(-4_i32, 0), Some(4));
+/// assert_eq!(gcd(i8::MIN, 0), None);
+/// ```
+pub fn gcd<T: Integer>(a: T, b: T) -> Option<T> {
+    let (mut a, mut b) = (a.checked_abs()?, b.checked_abs()?);
+    while b != T::ZERO {
+        let r = a.checked_rem_euclid(b)?;
+        a = b;
+        b = r;
+    }
+    Some(a)
+}
+
+/// Least common multiple, never negative
+///
+/// ```
+/// # use advent2020::numtheory::lcm;
+/// assert_eq!(lcm(4_u8, 6), Some(12));
+/// assert_eq!(lcm(0, 6), Some(0));
+/// assert_eq!(lcm(200_u8, 3), None);
+/// ```
+pub fn lcm<T: Integer>(a: T, b: T) -> Option<T> {
+    if a == T::ZERO || b == T::ZERO {
+        return Some(T::ZERO);
+    }
+    a.checked_abs()?
+        .checked_div(gcd(a, b)?)?
+        .checked_mul(b.checked_abs()?)
+}
+
+/// `(g, x, y)` with `a x + b y = g = gcd(a, b)`
+///
+/// The coefficients are usually negative, so unsigned types mostly give `None`.
+///
+/// ```
+/// # use advent2020::numtheory::extended_gcd;
+/// assert_eq!(extended_gcd(12, 42), Some((6, -3, 1)));
+/// ```
+pub fn extended_gcd<T: Integer>(a: T, b: T) -> Option<(T, T, T)> {
+    let (mut old_r, mut curr_r) = (a, b);
+    let (mut old_s, mut curr_s) = (T::ONE, T::ZERO);
+    let (mut old_t, mut curr_t) = (T::ZERO, T::ONE);
+    while curr_r != T::ZERO {
+        let quotient = old_r.checked_div(curr_r)?;
+        let new_r = old_r.checked_sub(quotient.checked_mul(curr_r)?)?;
+        old_r = curr_r;
+        curr_r = new_r;
+        let new_s = old_s.checked_sub(quotient.checked_mul(curr_s)?)?;
+        old_s = curr_s;
+        curr_s = new_s;
+        let new_t = old_t.checked_sub(quotient.checked_mul(curr_t)?)?;
+        old_t = curr_t;
+        curr_t = new_t;
+    }
+    if old_r < T::ZERO {
+        let neg = |v: T| T::ZERO.checked_sub(v);
+        Some((neg(old_r)?, neg(old_s)?, neg(old_t)?))
+    } else {
+        Some((old_r, old_s, old_t))
+    }
+}
+
+fn check_modulus<T: Integer>(m: T) -> Option<T> {
+    if m > T::ZERO {
+        Some(m)
+    } else {
+        None
+    }
+}
+
+/// `a b mod m`
+pub fn mul_mod<T: Integer>(a: T, b: T, m: T) -> Option<T> {
+    let m = check_modulus(m)?;
+    a.checked_rem_euclid(m)?
+        .checked_mul(b.checked_rem_euclid(m)?)?
+        .checked_rem_euclid(m)
+}
+
+/// `a - b mod m`, for `a` and `b` already in `0..m`
+fn sub_mod<T: Integer>(a: T, b: T, m: T) -> Option<T> {
+    if a >= b {
+        a.checked_sub(b)
+    } else {
+        m.checked_sub(b)?.checked_add(a)
+    }
+}
+
+/// `base^exp mod m` by repeated squaring
+///
+/// ```
+/// # use advent2020::numtheory::mod_pow;
+/// assert_eq!(mod_pow(7_u64, 8, 20201227), Some(5764801));
+/// assert_eq!(mod_pow(3, 0, 1), Some(0));
+/// assert_eq!(mod_pow(2, -1, 5), None);
+/// ```
+pub fn mod_pow<T: Integer>(base: T, exp: T, m: T) -> Option<T> {
+    let m = check_modulus(m)?;
+    if exp < T::ZERO {
+        return None;
+    }
+    let two = T::ONE.checked_add(T::ONE)?;
+    let mut result = T::ONE.checked_rem_euclid(m)?;
+    let mut base = base.checked_rem_euclid(m)?;
+    let mut exp = exp;
+    while exp > T::ZERO {
+        if exp.checked_rem_euclid(two)? == T::ONE {
+            result = mul_mod(result, base, m)?;
+        }
+        exp = exp.checked_div(two)?;
+        if exp > T::ZERO {
+            base = mul_mod(base, base, m)?;
+        }
+    }
+    Some(result)
+}
+
+/// `x` in `0..m` with `a x = 1 mod m`, if `a` and `m` are coprime
+///
+/// ```
+/// # use advent2020::numtheory::mod_inverse;
+/// assert_eq!(mod_inverse(3_u32, 7), Some(5));
+/// assert_eq!(mod_inverse(-3, 7), Some(2));
+/// assert_eq!(mod_inverse(4, 6), None);
+/// ```
+pub fn mod_inverse<T: Integer>(a: T, m: T) -> Option<T> {
+    let m = check_modulus(m)?;
+    // Extended Euclid on the magnitudes of the coefficients, whose signs alternate,
+    // so they stay below `m` and nothing overflows even for unsigned types
+    let (mut old_r, mut curr_r) = (a.checked_rem_euclid(m)?, m);
+    let (mut old_s, mut curr_s) = (T::ONE, T::ZERO);
+    let mut negative = false;
+    while curr_r != T::ZERO {
+        let quotient = old_r.checked_div(curr_r)?;
+        let new_r = old_r.checked_sub(quotient.checked_mul(curr_r)?)?;
+        old_r = curr_r;
+        curr_r = new_r;
+        let new_s = old_s.checked_add(quotient.checked_mul(curr_s)?)?;
+        old_s = curr_s;
+        curr_s = new_s;
+        negative = !negative;
+    }
+    if old_r != T::ONE && m != T::ONE {
+        return None;
+    }
+    let old_s = old_s.checked_rem_euclid(m)?;
+    if negative {
+        sub_mod(T::ZERO, old_s, m)
+    } else {
+        Some(old_s)
+    }
+}
+
+/// Chinese remainder theorem for `x = r_i mod m_i`
+///
+/// Moduli need not be coprime, returns `(x, lcm(m_i))` with `x` the smallest
+/// non-negative solution, or `None` if the congruences contradict each other.
+///
+/// ```
+/// # use advent2020::numtheory::crt;
+/// assert_eq!(crt(&[(0, 3), (3, 4), (4, 5)]), Some((39, 60)));
+/// assert_eq!(crt(&[(1_u8, 4), (3, 6)]), Some((9, 12)));
+/// assert_eq!(crt(&[(0, 4), (1, 6)]), None);
+/// ```
+pub fn crt<T: Integer>(congruences: &[(T, T)]) -> Option<(T, T)> {
+    congruences
+        .iter()
+        .try_fold((T::ZERO, T::ONE), |(r1, m1), &(r2, m2)| {
+            let m2 = check_modulus(m2)?;
+            let r2 = r2.checked_rem_euclid(m2)?;
+            let g = gcd(m1, m2)?;
+            let diff = sub_mod(r2, r1.checked_rem_euclid(m2)?, m2)?;
+            if diff.checked_rem_euclid(g)? != T::ZERO {
+                return None;
+            }
+            // r1 + m1 k = r2 (mod m2), so k = diff / g * (m1 / g)^-1 (mod m2 / g)
+            let m2_g = m2.checked_div(g)?;
+            let inverse = mod_inverse(m1.checked_div(g)?, m2_g)?;
+            let k = mul_mod(diff.checked_div(g)?, inverse, m2_g)?;
+            let m = m1.checked_mul(m2_g)?;
+            Some((
+                r1.checked_add(m1.checked_mul(k)?)?.checked_rem_euclid(m)?,
+                m,
+            ))
+        })
+}
+
+/// Smallest `x >= 0` with `base^x = target mod m`, by baby-step giant-step
+///
+/// ```
+/// # use advent2020::numtheory::discrete_log;
+/// assert_eq!(discrete_log(7_u64, 5764801, 20201227), Some(8));
+/// assert_eq!(discrete_log(2, 3, 7), None);
+/// ```
+pub fn discrete_log<T: Integer>(base: T, target: T, m: T) -> Option<T> {
+    let m = check_modulus(m)?;
+    let target = target.checked_rem_euclid(m)?;
+    if target == T::ONE.checked_rem_euclid(m)? {
+        return Some(T::ZERO);
+    }
+    // Smallest n with n² >= m
+    let mut n = T::ONE;
+    while let Some(n2) = n.checked_mul(n) {
+        if n2 >= m {
+            break;
+        }
+        n = n.checked_add(T::ONE)?;
+    }
+    // target × base^j for j in 0..n, keeping the largest j
+    let mut baby_steps = HashMap::new();
+    let mut value = target;
+    let mut j = T::ZERO;
+    while j < n {
+        baby_steps.insert(value, j);
+        value = mul_mod(value, base, m)?;
+        j = j.checked_add(T::ONE)?;
+    }
+    // base^(n i) = target × base^j gives x = n i - j
+    let giant_step = mod_pow(base, n, m)?;
+    let mut value = T::ONE.checked_rem_euclid(m)?;
+    let mut i = T::ONE;
+    while i <= n {
+        value = mul_mod(value, giant_step, m)?;
+        if let Some(&j) = baby_steps.get(&value) {
+            let x = n.checked_mul(i)?.checked_sub(j)?;
+            if mod_pow(base, x, m)? == target {
+                return Some(x);
+            }
+        }
+        i = i.checked_add(T::ONE)?;
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_extended_gcd() {
+        assert_eq!(extended_gcd(12, 42), Some((6, -3, 1)));
+        assert_eq!(extended_gcd(3, 4), Some((1, -1, 1)));
+        assert_eq!(extended_gcd(-4, 6), Some((2, 1, 1)));
+        assert_eq!(extended_gcd(3_u8, 4), None);
+    }
+
+    #[test]
+    fn test_crt() {
+        assert_eq!(crt(&[(0, 3), (3, 4)]), Some((3, 12)));
+        assert_eq!(crt(&[(4, 5), (3, 12)]), Some((39, 60)));
+        assert_eq!(crt(&[(-1_i8, 5)]), Some((4, 5)));
+        assert_eq!(crt::<i32>(&[]), Some((0, 1)));
+        assert_eq!(crt(&[(0_u8, 16), (1, 17)]), None);
+    }
+
+    #[test]
+    fn test_invalid_modulus() {
+        assert_eq!(mod_pow(2, 3, 0), None);
+        assert_eq!(mod_inverse(2, -3), None);
+        assert_eq!(crt(&[(1, 0)]), None);
+        assert_eq!(discrete_log(2, 1, 0), None);
+    }
+}
@@ -0,0 +1,461 @@
+//! Zero-copy helpers for the shapes puzzle inputs come in
+//!
+//! Every piece of input is handed out as a [`Spanned`] slice that remembers
+//! where it came from, so errors can point at the offending line and column.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
+
+use colored::*;
+
+/// Location of a slice of input, lines and columns are 1-based
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Span {
+    pub line: usize,
+    pub col: usize,
+    /// Length in characters
+    pub len: usize,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParseError {
+    pub span: Span,
+    pub message: String,
+    /// The format that was expected, e.g. `expected one of nop|acc|jmp`
+    pub hint: Option<String>,
+}
+
+impl ParseError {
+    /// Just past the last line of `input`, for something missing at the end
+    pub fn at_end<M: ToString>(input: &str, message: M) -> Self {
+        ParseError {
+            span: Span {
+                line: input.lines().count() + 1,
+                col: 1,
+                len: 0,
+            },
+            message: message.to_string(),
+            hint: None,
+        }
+    }
+
+    pub fn with_hint<H: ToString>(mut self, hint: H) -> Self {
+        self.hint = Some(hint.to_string());
+        self
+    }
+
+    /// A diagnostic quoting the offending line of `input`, read from `file`
+    ///
+    /// ```
+    /// # use advent2020::parse::lines;
+    /// colored::control::set_override(false);
+    /// let input = "acc +1\nhop +2";
+    /// let line = lines(input).nth(1).unwrap();
+    /// let error = line.slice(&line.value[..3]).error("unknown operation `hop`");
+    /// assert_eq!(
+    ///     error.with_hint("expected one of nop|acc|jmp").render("day8.txt", input),
+    ///     "\
+    /// error: unknown operation `hop`
+    ///  --> day8.txt:2:1
+    ///   |
+    /// 2 | hop +2
+    ///   | ^^^
+    ///   = hint: expected one of nop|acc|jmp
+    /// "
+    /// );
+    /// ```
+    pub fn render(&self, file: &str, input: &str) -> String {
+        let Span { line, col, len } = self.span;
+        let source = input.lines().nth(line - 1).unwrap_or("");
+        let number = line.to_string();
+        let gutter = " ".repeat(number.len());
+        let bar = "|".blue().bold();
+        let mut out = format!("{}: {}\n", "error".red().bold(), self.message.bold());
+        out += &format!(
+            "{}{} {}:{}:{}\n",
+            gutter,
+            "-->".blue().bold(),
+            file,
+            line,
+            col
+        );
+        out += &format!("{} {}\n", gutter, bar);
+        out += &format!("{} {} {}\n", number.blue().bold(), bar, source);
+        out += &format!(
+            "{} {} {}{}\n",
+            gutter,
+            bar,
+            " ".repeat(col - 1),
+            "^".repeat(len.max(1)).red().bold()
+        );
+        if let Some(hint) = &self.hint {
+            out += &format!("{} {} {}\n", gutter, "= hint:".bold(), hint);
+        }
+        out
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {}",
+            self.span.line, self.span.col, self.message
+        )?;
+        match &self.hint {
+            Some(hint) => write!(f, "; {}", hint),
+            None => Ok(()),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// A slice of input along with its location
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Spanned<'a> {
+    pub value: &'a str,
+    pub span: Span,
+}
+
+impl<'a> Spanned<'a> {
+    fn new(value: &'a str, line: usize, col: usize) -> Self {
+        Spanned {
+            value,
+            span: Span {
+                line,
+                col,
+                len: value.chars().count(),
+            },
+        }
+    }
+
+    /// Re-locates `sub`, which must be a slice of `self.value`
+    pub fn slice(&self, sub: &'a str) -> Spanned<'a> {
+        let offset = (sub.as_ptr() as usize)
+            .checked_sub(self.value.as_ptr() as usize)
+            .filter(|offset| offset + sub.len() <= self.value.len())
+            .expect("not a slice of this input");
+        Spanned::new(
+            sub,
+            self.span.line,
+            self.span.col + self.value[..offset].chars().count(),
+        )
+    }
+
+    pub fn error<M: ToString>(&self, message: M) -> ParseError {
+        ParseError {
+            span: self.span,
+            message: message.to_string(),
+            hint: None,
+        }
+    }
+
+    pub fn parse<T>(&self) -> Result<T, ParseError>
+    where
+        T: FromStr,
+        T::Err: fmt::Display,
+    {
+        self.value
+            .parse()
+            .map_err(|e| self.error(format!("invalid value `{}`: {}", self.value, e)))
+    }
+
+    pub fn trim(&self) -> Spanned<'a> {
+        self.slice(self.value.trim())
+    }
+
+    pub fn split(&self, separator: char) -> impl Iterator<Item = Spanned<'a>> + '_ {
+        self.value.split(separator).map(move |s| self.slice(s))
+    }
+
+    pub fn split_whitespace(&self) -> impl Iterator<Item = Spanned<'a>> + '_ {
+        self.value.split_whitespace().map(move |s| self.slice(s))
+    }
+
+    pub fn split_once(&self, separator: char) -> Option<(Spanned<'a>, Spanned<'a>)> {
+        let idx = self.value.find(separator)?;
+        Some((
+            self.slice(&self.value[..idx]),
+            self.slice(&self.value[idx + separator.len_utf8()..]),
+        ))
+    }
+
+    /// Requires the slice to be exactly `expected`
+    pub fn expect(&self, expected: &str) -> Result<(), ParseError> {
+        if self.value == expected {
+            Ok(())
+        } else {
+            Err(self.error(format!("expected `{}`, got `{}`", expected, self.value)))
+        }
+    }
+
+    /// Characters along with their own spans
+    pub fn chars(&self) -> impl Iterator<Item = (char, Spanned<'a>)> + '_ {
+        // Columns counted as we go, re-slicing would count from the start each time
+        self.value
+            .char_indices()
+            .enumerate()
+            .map(move |(n, (idx, c))| {
+                let value = &self.value[idx..idx + c.len_utf8()];
+                (c, Spanned::new(value, self.span.line, self.span.col + n))
+            })
+    }
+
+    /// Splits off everything up to the first `separator`, which is dropped
+    ///
+    /// Unlike [`split_once`](Self::split_once) the separator can be longer than a character.
+    pub fn split_at_str(&self, separator: &str) -> Option<(Spanned<'a>, Spanned<'a>)> {
+        let idx = self.value.find(separator)?;
+        Some((
+            self.slice(&self.value[..idx]),
+            self.slice(&self.value[idx + separator.len()..]),
+        ))
+    }
+
+    /// The rest of the slice after `prefix`, which must be there
+    pub fn strip_prefix(&self, prefix: &str) -> Result<Spanned<'a>, ParseError> {
+        match self.value.strip_prefix(prefix) {
+            Some(rest) => Ok(self.slice(rest)),
+            None => Err(self.error(format!("expected `{}` at the start", prefix))),
+        }
+    }
+}
+
+/// All lines of the input
+pub fn lines(input: &str) -> impl Iterator<Item = Spanned<'_>> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(idx, line)| Spanned::new(line, idx + 1, 1))
+}
+
+/// Non-empty lines, each run of them is ended by a blank line
+pub type Paragraph<'a> = Vec<Spanned<'a>>;
+
+/// Groups of lines separated by blank lines
+///
+/// ```
+/// # use advent2020::parse::paragraphs;
+/// let groups = paragraphs("abc\n\na\nb\n\n\nc\n");
+/// assert_eq!(groups.len(), 3);
+/// assert_eq!(groups[1][1].value, "b");
+/// assert_eq!(groups[1][1].span.line, 4);
+/// ```
+pub fn paragraphs(input: &str) -> Vec<Paragraph<'_>> {
+    let mut groups = vec![];
+    let mut group = vec![];
+    for line in lines(input) {
+        if line.value.trim().is_empty() {
+            if !group.is_empty() {
+                groups.push(std::mem::take(&mut group));
+            }
+        } else {
+            group.push(line);
+        }
+    }
+    if !group.is_empty() {
+        groups.push(group);
+    }
+    groups
+}
+
+/// One number per line, at least one
+///
+/// ```
+/// # use advent2020::parse::numbers;
+/// assert_eq!(numbers::<u32>("1\n22\n"), Ok(vec![1, 22]));
+/// assert_eq!(numbers::<u32>("1\nx").unwrap_err().span.line, 2);
+/// assert_eq!(numbers::<u32>("").unwrap_err().message, "no numbers");
+/// ```
+pub fn numbers<T>(input: &str) -> Result<Vec<T>, ParseError>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    let hint = "expected one number per line";
+    let numbers: Vec<T> = lines(input)
+        .map(|line| line.parse().map_err(|e| e.with_hint(hint)))
+        .collect::<Result<_, _>>()?;
+    if numbers.is_empty() {
+        return Err(ParseError::at_end(input, "no numbers").with_hint(hint));
+    }
+    Ok(numbers)
+}
+
+/// Comma separated values, surrounding whitespace is ignored
+///
+/// ```
+/// # use advent2020::parse::{comma_list, lines};
+/// let line = lines("1, 2,x").next().unwrap();
+/// assert_eq!(comma_list::<usize>(line.slice(&line.value[..4])), Ok(vec![1, 2]));
+/// let error = comma_list::<usize>(line).unwrap_err();
+/// assert_eq!(error.span.col, 6);
+/// ```
+pub fn comma_list<T>(s: Spanned<'_>) -> Result<Vec<T>, ParseError>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    s.trim().split(',').map(|v| v.trim().parse()).collect()
+}
+
+/// The `key:value` pairs of a [`record`] in order, without collecting them
+pub fn pairs<'a, 'b>(
+    lines: &'b [Spanned<'a>],
+) -> impl Iterator<Item = Result<(Spanned<'a>, Spanned<'a>), ParseError>> + 'b {
+    lines
+        .iter()
+        .flat_map(|line| line.split_whitespace())
+        .map(|pair| {
+            pair.split_once(':')
+                .ok_or_else(|| pair.error(format!("expected `key:value`, got `{}`", pair.value)))
+        })
+}
+
+/// Whitespace separated `key:value` pairs, possibly over several lines
+///
+/// ```
+/// # use advent2020::parse::{paragraphs, record};
+/// let input = "ecl:gry pid:860033327\nbyr:1937";
+/// let fields = record(&paragraphs(input)[0]).unwrap();
+/// assert_eq!(fields["pid"].value, "860033327");
+/// assert_eq!(fields["byr"].span.line, 2);
+/// ```
+pub fn record<'a>(lines: &[Spanned<'a>]) -> Result<HashMap<&'a str, Spanned<'a>>, ParseError> {
+    let mut fields = HashMap::new();
+    for pair in pairs(lines) {
+        let (key, value) = pair?;
+        if fields.insert(key.value, value).is_some() {
+            return Err(key.error(format!("duplicate key `{}`", key.value)));
+        }
+    }
+    Ok(fields)
+}
+
+/// A single character of a grid input
+pub trait Cell: Sized {
+    /// Accepted characters, listed in error messages
+    const CHARS: &'static str;
+
+    fn from_char(c: char) -> Option<Self>;
+}
+
+/// Rectangular grid of cells, as rows
+pub fn grid<C: Cell>(input: &str) -> Result<Vec<Vec<C>>, ParseError> {
+    let mut rows: Vec<Vec<C>> = vec![];
+    for line in lines(input) {
+        let mut row = Vec::with_capacity(rows.first().map_or(line.value.len(), Vec::len));
+        for (c, span) in line.chars() {
+            row.push(C::from_char(c).ok_or_else(|| {
+                span.error(format!("expected one of `{}`, got `{}`", C::CHARS, c))
+            })?);
+        }
+        if let Some(first) = rows.first() {
+            if row.len() != first.len() {
+                return Err(line.error(format!(
+                    "expected {} cells like the first row, got {}",
+                    first.len(),
+                    row.len()
+                )));
+            }
+        }
+        rows.push(row);
+    }
+    if rows.is_empty() {
+        return Err(ParseError::at_end(input, "empty grid"));
+    }
+    Ok(rows)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, PartialEq)]
+    enum Seat {
+        Floor,
+        Empty,
+    }
+
+    impl Cell for Seat {
+        const CHARS: &'static str = ".L";
+
+        fn from_char(c: char) -> Option<Self> {
+            match c {
+                '.' => Some(Seat::Floor),
+                'L' => Some(Seat::Empty),
+                _ => None,
+            }
+        }
+    }
+
+    #[test]
+    fn test_spans() {
+        let line = lines("\n  ab, é,cd").nth(1).unwrap();
+        let items: Vec<_> = line.split(',').map(|s| s.trim()).collect();
+        assert_eq!(items[1].value, "é");
+        assert_eq!(
+            items[1].span,
+            Span {
+                line: 2,
+                col: 7,
+                len: 1
+            }
+        );
+        assert_eq!(
+            items[2].span,
+            Span {
+                line: 2,
+                col: 9,
+                len: 2
+            }
+        );
+        let error = items[0].parse::<usize>().unwrap_err();
+        assert_eq!(
+            error.span,
+            Span {
+                line: 2,
+                col: 3,
+                len: 2
+            }
+        );
+        assert!(error
+            .to_string()
+            .starts_with("line 2, column 3: invalid value `ab`"));
+    }
+
+    #[test]
+    fn test_prefixes() {
+        let line = lines("mask = X1").next().unwrap();
+        let mask = line.strip_prefix("mask = ").unwrap();
+        assert_eq!((mask.value, mask.span.col), ("X1", 8));
+        assert_eq!(mask.chars().nth(1).unwrap().1.span.col, 9);
+        assert_eq!(line.strip_prefix("mem").unwrap_err().span.col, 1);
+        let (outer, inner) = line.split_at_str(" = ").unwrap();
+        assert_eq!((outer.value, inner.span.col), ("mask", 8));
+        assert!(line.split_at_str(" contain ").is_none());
+    }
+
+    #[test]
+    fn test_record_errors() {
+        let groups = paragraphs("a:1 b\n");
+        assert_eq!(record(&groups[0]).unwrap_err().span.col, 5);
+        let groups = paragraphs("a:1\nc:3 a:2");
+        let error = record(&groups[0]).unwrap_err();
+        assert_eq!((error.span.line, error.span.col), (2, 5));
+    }
+
+    #[test]
+    fn test_grid() {
+        let rows: Vec<Vec<Seat>> = grid("L.\n.L").unwrap();
+        assert_eq!(rows[1], vec![Seat::Floor, Seat::Empty]);
+        let error = grid::<Seat>("L.\n.#").unwrap_err();
+        assert_eq!((error.span.line, error.span.col), (2, 2));
+        assert_eq!(error.message, "expected one of `.L`, got `#`");
+        let error = grid::<Seat>("L.\n.").unwrap_err();
+        assert_eq!(error.span.line, 2);
+        assert!(grid::<Seat>("").is_err());
+    }
+}
@@ -0,0 +1,147 @@
+//! Interactive queries against a day's parsed input
+//!
+//! Days that support it register a constructor with their [`Puzzle`](crate::Puzzle),
+//! which parses the input once into a [`Session`].
+
+use std::fmt;
+use std::io::{self, BufRead, Write};
+use std::str::FromStr;
+
+use crate::config::Config;
+
+#[derive(Debug, PartialEq)]
+pub enum ReplError {
+    UnknownCommand(String),
+    /// Wrong arguments, answered with the command's usage
+    Usage,
+    Invalid(String),
+}
+
+impl fmt::Display for ReplError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownCommand(s) => write!(f, "unknown command `{}`, try `help`", s),
+            Self::Usage => write!(f, "wrong arguments"),
+            Self::Invalid(s) => write!(f, "{}", s),
+        }
+    }
+}
+
+impl std::error::Error for ReplError {}
+
+/// A day's parsed input, queried one command at a time
+pub trait Session {
+    /// Usage and description of every command, e.g. `("turn <k>", "number spoken at turn k")`
+    fn commands(&self) -> &'static [(&'static str, &'static str)];
+
+    fn eval(&mut self, command: &str, args: &[&str]) -> Result<String, ReplError>;
+}
+
+pub type Open = fn(&str, &Config) -> Box<dyn Session>;
+
+/// Parses argument `idx`, a missing or malformed one is a usage error
+pub fn arg<T: FromStr>(args: &[&str], idx: usize) -> Result<T, ReplError> {
+    args.get(idx)
+        .and_then(|arg| arg.parse().ok())
+        .ok_or(ReplError::Usage)
+}
+
+fn help(session: &dyn Session) -> String {
+    let commands = session
+        .commands()
+        .iter()
+        .chain(&[("help", "this list"), ("quit", "leave")]);
+    commands
+        .map(|(usage, description)| format!("  {:<24} {}", usage, description))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Evaluates one line, `None` when it asks to quit
+pub fn eval_line(session: &mut dyn Session, line: &str) -> Option<Result<String, ReplError>> {
+    let words: Vec<&str> = line.split_whitespace().collect();
+    let (&command, args) = match words.split_first() {
+        Some(split) => split,
+        None => return Some(Ok(String::new())),
+    };
+    match command {
+        "quit" | "exit" => None,
+        "help" => Some(Ok(help(session))),
+        _ => Some(session.eval(command, args).map_err(|e| {
+            match e {
+                ReplError::Usage => session
+                    .commands()
+                    .iter()
+                    .find(|(usage, _)| usage.split_whitespace().next() == Some(command))
+                    .map_or(e, |(usage, _)| {
+                        ReplError::Invalid(format!("usage: {}", usage))
+                    }),
+                e => e,
+            }
+        })),
+    }
+}
+
+/// Reads commands from `input` until it ends or asks to quit
+pub fn run<R: BufRead, W: Write>(
+    session: &mut dyn Session,
+    prompt: &str,
+    input: R,
+    mut output: W,
+) -> io::Result<()> {
+    write!(output, "{}", prompt)?;
+    output.flush()?;
+    for line in input.lines() {
+        match eval_line(session, &line?) {
+            None => return Ok(()),
+            Some(Ok(s)) if s.is_empty() => (),
+            Some(Ok(s)) => writeln!(output, "{}", s)?,
+            Some(Err(e)) => writeln!(output, "error: {}", e)?,
+        }
+        write!(output, "{}", prompt)?;
+        output.flush()?;
+    }
+    writeln!(output)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Counter(i64);
+
+    impl Session for Counter {
+        fn commands(&self) -> &'static [(&'static str, &'static str)] {
+            &[("add <n>", "add n to the total")]
+        }
+
+        fn eval(&mut self, command: &str, args: &[&str]) -> Result<String, ReplError> {
+            match command {
+                "add" => {
+                    self.0 += arg::<i64>(args, 0)?;
+                    Ok(self.0.to_string())
+                }
+                _ => Err(ReplError::UnknownCommand(command.to_string())),
+            }
+        }
+    }
+
+    #[test]
+    fn test_run() {
+        let input = "add 2\n\nadd x\nadd 40\nsub 1\nhelp\nquit\nadd 1\n";
+        let mut output = Vec::new();
+        run(&mut Counter(0), "> ", input.as_bytes(), &mut output).unwrap();
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "\
+> 2
+> > error: usage: add <n>
+> 42
+> error: unknown command `sub`, try `help`
+>   add <n>                  add n to the total
+  help                     this list
+  quit                     leave
+> "
+        );
+    }
+}
@@ -0,0 +1,98 @@
+//! Leveled trace output from the solvers
+//!
+//! Every day wraps parsing and each part in a span and emits events per step,
+//! all under the day's module path so they can be switched on one day at a time.
+
+use std::fmt;
+
+use tracing::level_filters::LevelFilter;
+use tracing_subscriber::fmt::format::FmtSpan;
+use tracing_subscriber::EnvFilter;
+
+#[derive(Debug, PartialEq)]
+pub enum TraceError {
+    Syntax(String),
+    Day(String),
+    Level(String),
+}
+
+impl fmt::Display for TraceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Syntax(s) => write!(f, "expected `<day>=<level>`, got `{}`", s),
+            Self::Day(s) => write!(f, "expected a day number or `all`, got `{}`", s),
+            Self::Level(s) => write!(
+                f,
+                "expected one of off|error|warn|info|debug|trace, got `{}`",
+                s
+            ),
+        }
+    }
+}
+
+impl std::error::Error for TraceError {}
+
+/// Filter directive for a `<day>=<level>` flag, the day being `11`, `day11` or `all`
+///
+/// ```
+/// # use advent2020::trace::directive;
+/// assert_eq!(directive(2020, "day11=debug").unwrap(), "advent2020::y2020::day11=debug");
+/// assert_eq!(directive(2020, "all=info").unwrap(), "advent2020=info");
+/// ```
+pub fn directive(year: u16, flag: &str) -> Result<String, TraceError> {
+    let (day, level) = match flag.find('=') {
+        Some(idx) => (&flag[..idx], &flag[idx + 1..]),
+        None => return Err(TraceError::Syntax(flag.to_string())),
+    };
+    let level: LevelFilter = level
+        .parse()
+        .map_err(|_| TraceError::Level(level.to_string()))?;
+    let target = if day == "all" {
+        "advent2020".to_string()
+    } else {
+        let number: usize = day
+            .trim_start_matches("day")
+            .parse()
+            .map_err(|_| TraceError::Day(day.to_string()))?;
+        format!("advent2020::y{}::day{}", year, number)
+    };
+    Ok(format!("{}={}", target, level).to_lowercase())
+}
+
+/// Writes matching events to stderr, and spans with their timings when they close
+///
+/// Nothing is installed when `flags` is empty.
+pub fn init(year: u16, flags: &[String]) -> Result<(), TraceError> {
+    if flags.is_empty() {
+        return Ok(());
+    }
+    let mut filter = EnvFilter::default();
+    for flag in flags {
+        filter = filter.add_directive(directive(year, flag)?.parse().unwrap());
+    }
+    tracing_subscriber::fmt()
+        .with_env_filter(filter)
+        .with_span_events(FmtSpan::CLOSE)
+        .with_writer(std::io::stderr)
+        .init();
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_directive() {
+        assert_eq!(
+            directive(2020, "7=TRACE"),
+            Ok("advent2020::y2020::day7=trace".to_string())
+        );
+        assert!(matches!(directive(2020, "7"), Err(TraceError::Syntax(_))));
+        assert!(matches!(directive(2020, "x=info"), Err(TraceError::Day(_))));
+        assert!(matches!(
+            directive(2020, "7=loud"),
+            Err(TraceError::Level(_))
+        ));
+    }
+}
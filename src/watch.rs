@@ -0,0 +1,103 @@
+//! Polling watcher over input files and input-set directories
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+/// Modification time and length of every watched file
+type Snapshot = BTreeMap<PathBuf, (SystemTime, u64)>;
+
+fn record(snapshot: &mut Snapshot, path: &Path) {
+    if let Ok(metadata) = fs::metadata(path) {
+        let modified = metadata.modified().unwrap_or(SystemTime::UNIX_EPOCH);
+        snapshot.insert(path.to_path_buf(), (modified, metadata.len()));
+    }
+}
+
+/// Files are watched as they are, directories by their direct entries
+fn snapshot(paths: &[PathBuf]) -> Snapshot {
+    let mut snapshot = Snapshot::new();
+    for path in paths {
+        match fs::read_dir(path) {
+            Ok(entries) => {
+                for entry in entries.flatten() {
+                    record(&mut snapshot, &entry.path());
+                }
+            }
+            Err(_) => record(&mut snapshot, path),
+        }
+    }
+    snapshot
+}
+
+#[derive(Debug)]
+pub struct Watcher {
+    paths: Vec<PathBuf>,
+    seen: Snapshot,
+    interval: Duration,
+}
+
+impl Watcher {
+    pub fn new(paths: Vec<PathBuf>, interval: Duration) -> Self {
+        let seen = snapshot(&paths);
+        Watcher {
+            paths,
+            seen,
+            interval,
+        }
+    }
+
+    /// Whether a file was changed, added or removed since the last poll
+    pub fn poll(&mut self) -> bool {
+        let current = snapshot(&self.paths);
+        let changed = current != self.seen;
+        self.seen = current;
+        changed
+    }
+
+    /// Blocks until a change, then until files stop changing so a save is seen once
+    pub fn wait(&mut self) {
+        while !self.poll() {
+            thread::sleep(self.interval);
+        }
+        thread::sleep(self.interval);
+        while self.poll() {
+            thread::sleep(self.interval);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_poll() {
+        let dir = std::env::temp_dir().join(format!("advent-watch-test-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let input = dir.join("day1.txt");
+        fs::write(&input, "1721\n").unwrap();
+
+        let mut file = Watcher::new(vec![input.clone()], Duration::from_millis(10));
+        let mut set = Watcher::new(vec![dir.clone()], Duration::from_millis(10));
+        assert!(!file.poll());
+        assert!(!set.poll());
+
+        // A different length, mtimes can be too coarse to tell writes apart
+        fs::write(&input, "1721\n979\n").unwrap();
+        assert!(file.poll());
+        assert!(!file.poll());
+        assert!(set.poll());
+
+        fs::write(dir.join("day2.txt"), "1-3 a: abcde\n").unwrap();
+        assert!(!file.poll());
+        assert!(set.poll());
+
+        fs::remove_file(&input).unwrap();
+        assert!(file.poll());
+        assert!(set.poll());
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
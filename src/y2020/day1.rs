@@ -0,0 +1,161 @@
+use itertools::Itertools;
+use serde::Deserialize;
+use tracing::{debug, instrument};
+
+use super::get_string;
+use crate::answer::Answer;
+use crate::config::{self, Config};
+use crate::memory::Lap;
+use crate::meta::Meta;
+use crate::parse::{numbers, ParseError};
+use crate::{Day, Part, Parts, Solution, SolveError};
+
+#[derive(Debug, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Params {
+    pub target: usize,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Params { target: 2020 }
+    }
+}
+
+impl config::Params for Params {}
+
+pub fn calc(expenses: Vec<usize>, combinations: usize, target: usize) -> Vec<Vec<usize>> {
+    expenses
+        .into_iter()
+        .combinations(combinations)
+        .filter(|v| v.iter().sum::<usize>() == target)
+        .collect()
+}
+
+#[instrument(skip_all)]
+pub fn parse(input: &str) -> Result<Vec<usize>, ParseError> {
+    numbers(input)
+}
+
+/// Product of the first `combinations` entries summing to `target`, `none` without any
+fn product_of_entries(expenses: &[usize], combinations: usize, target: usize) -> Solution<Answer> {
+    let mut matches = calc(expenses.to_vec(), combinations, target);
+    let tried = format!(
+        "Tried every {} of the {} entries",
+        combinations,
+        expenses.len()
+    );
+    let found = match matches.len() {
+        0 => {
+            let none = format!("No {} entries sum to {}", combinations, target);
+            return Solution::new(Answer::from("none"), none.clone())
+                .with_steps(vec![format!("{}, none sums to {}", tried, target)]);
+        }
+        1 => format!("{}, one sums to {}", tried, target),
+        n => format!("{}, {} sum to {}, taking the first", tried, n, target),
+    };
+    let values = &mut matches[0];
+    values.sort_unstable();
+    debug!(?values, "entries summing to the target");
+    let answer = values.iter().copied().product::<usize>();
+    let steps = vec![
+        found,
+        format!("{} = {}", values.iter().join(" + "), target),
+        format!("{} = {}", values.iter().join(" × "), answer),
+    ];
+    Solution::new(
+        Answer::from(answer),
+        format!(
+            "{} = {}",
+            answer,
+            values.iter().copied().map(|v| v.to_string()).join(" × "),
+        ),
+    )
+    .with_steps(steps)
+}
+
+#[instrument(skip_all)]
+pub fn part1(expenses: &[usize], params: &Params) -> Solution<Answer> {
+    product_of_entries(expenses, 2, params.target)
+}
+
+#[instrument(skip_all)]
+pub fn part2(expenses: &[usize], params: &Params) -> Solution<Answer> {
+    product_of_entries(expenses, 3, params.target)
+}
+
+pub const META: Meta = Meta {
+    title: "Report Repair",
+    tags: &[],
+    strategies: &["combinations of entries checked against the target"],
+    complexity: "O(nᵏ) for k entries",
+};
+
+pub fn solve(input: &str, config: &Config) -> Result<Day, SolveError> {
+    let mut lap = Lap::new();
+    let params: Params = config.checked("day1")?;
+    let expenses = parse(input)?;
+    let part1 = part1(&expenses, &params);
+    let part1_stats = lap.lap();
+    let part2 = part2(&expenses, &params);
+    let part2_stats = lap.lap();
+    Ok(Day {
+        stats: Parts(part1_stats, part2_stats),
+        ..Day::new(part1, part2)
+    })
+}
+
+/// The answer to a single part, without solving the other
+pub fn solve_part(input: &str, config: &Config, part: Part) -> Result<Answer, SolveError> {
+    let params: Params = config.checked("day1")?;
+    let expenses = parse(input)?;
+    Ok(match part {
+        Part::One => part1(&expenses, &params).answer,
+        Part::Two => part2(&expenses, &params).answer,
+    })
+}
+
+pub fn main(config: &Config) -> Result<Day, SolveError> {
+    solve(&get_string("day1.txt"), config)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::answer::Answer;
+
+    #[test]
+    fn test_main() {
+        let day = main(&Config::default()).unwrap();
+        assert_eq!(day.answers.0, Answer::Int(158916));
+        assert_eq!(day.answers.1, Answer::Int(165795564));
+    }
+
+    #[test]
+    fn test_example() {
+        let expenses = parse("1721\n979\n366\n299\n675\n1456").unwrap();
+        assert_eq!(
+            part1(&expenses, &Params::default()).answer,
+            Answer::Int(514579)
+        );
+        assert_eq!(
+            part2(&expenses, &Params::default()).answer,
+            Answer::Int(241861950)
+        );
+        let params = Params { target: 1 };
+        let none = part1(&expenses, &params);
+        assert_eq!(
+            (none.answer, none.display.as_str()),
+            (Answer::from("none"), "No 2 entries sum to 1")
+        );
+        let first = part1(&[1, 2, 3, 4], &Params { target: 5 });
+        assert_eq!(first.answer, Answer::Int(4));
+        assert!(first.steps[0].ends_with("2 sum to 5, taking the first"));
+    }
+
+    #[test]
+    fn test_parse() {
+        let error = solve("", &Config::default()).unwrap_err();
+        assert!(matches!(error, SolveError::Parse(e) if e.message == "no numbers"));
+    }
+}
@@ -0,0 +1,231 @@
+use itertools::Itertools;
+use std::cmp;
+use std::collections::HashMap;
+use tracing::{debug, instrument};
+
+use super::get_string;
+use crate::answer::Answer;
+use crate::config::Config;
+use crate::memory::Lap;
+use crate::meta::{Meta, Tag};
+use crate::parse::{numbers, ParseError};
+use crate::{Day, Part, Parts, Solution, SolveError};
+
+/// Sorted joltages, including the outlet and the device
+#[instrument(skip_all)]
+pub fn parse(input: &str) -> Result<Vec<usize>, ParseError> {
+    let mut numbers: Vec<usize> = numbers(input)?;
+    numbers.push(0);
+    numbers.sort_unstable();
+    numbers.push(numbers.last().unwrap() + N);
+    Ok(numbers)
+}
+
+const N: usize = 3;
+
+/// Exhaustive count combinations
+pub fn exhaustive_count(sorted_numbers: Vec<usize>) -> usize {
+    let min = cmp::max(sorted_numbers.len() / N, 1);
+    let max = sorted_numbers.len();
+    (min..=max)
+        .flat_map(|combos| sorted_numbers.iter().copied().combinations(combos))
+        .filter(|combo| {
+            combo.first().unwrap() == sorted_numbers.first().unwrap()
+                && combo.last().unwrap() == sorted_numbers.last().unwrap()
+        })
+        .filter(|combo| combo.windows(2).map(|w| w[1] - w[0]).all(|diff| diff <= N))
+        .count()
+}
+
+/// Runs between adapters with a gap of `N` on both sides, which every arrangement keeps
+fn sections(sorted_numbers: &[usize]) -> Vec<&[usize]> {
+    let diffs: Vec<usize> = sorted_numbers.windows(2).map(|w| w[1] - w[0]).collect();
+    std::iter::once(0)
+        .chain(diffs.windows(2).enumerate().filter_map(|(idx, w)| {
+            if w[0] == N && w[1] == N {
+                Some(idx + 1)
+            } else {
+                None
+            }
+        }))
+        .chain(std::iter::once(sorted_numbers.len() - 1))
+        .collect::<Vec<usize>>()
+        .windows(2)
+        .map(|idxs| sorted_numbers.get(idxs[0]..=idxs[1]).unwrap())
+        .collect()
+}
+
+/// Obvious section
+pub fn section_count(sorted_numbers: Vec<usize>) -> usize {
+    sections(&sorted_numbers)
+        .into_iter()
+        .map(|slice| {
+            let arrangements = exhaustive_count(slice.to_vec());
+            debug!(section = ?slice, arrangements, "section");
+            arrangements
+        })
+        .product()
+}
+
+#[instrument(skip_all)]
+pub fn part1(numbers: &[usize]) -> Solution<usize> {
+    let mut counts = HashMap::new();
+    for difference in numbers.windows(2).map(|w| w[1] - w[0]) {
+        counts
+            .entry(difference)
+            .and_modify(|e| *e += 1)
+            .or_insert(1);
+    }
+    let answer = counts.get(&1).unwrap() * counts.get(&N).unwrap();
+    let steps = vec![
+        format!(
+            "Chained {} adapters between the outlet at 0 and the device at {}",
+            numbers.len() - 2,
+            numbers.last().unwrap()
+        ),
+        format!(
+            "{} differences of 1 jolt and {} of {} jolts",
+            counts[&1], counts[&N], N
+        ),
+        format!("{} × {} = {}", counts[&1], counts[&N], answer),
+    ];
+    Solution::new(answer, format!("{} jolts", answer)).with_steps(steps)
+}
+
+#[instrument(skip_all)]
+pub fn part2(numbers: &[usize]) -> Solution<usize> {
+    let sections = sections(numbers);
+    let mut steps = vec![format!(
+        "Split the chain into {} sections at adapters {} jolts from both neighbours",
+        sections.len(),
+        N
+    )];
+    let mut distinct_arrangements = 1;
+    for (idx, slice) in sections.iter().enumerate() {
+        let arrangements = exhaustive_count(slice.to_vec());
+        distinct_arrangements *= arrangements;
+        steps.push(format!(
+            "Section {}: {}..={} ({} joltages) has {} arrangement{}",
+            idx + 1,
+            slice.first().unwrap(),
+            slice.last().unwrap(),
+            slice.len(),
+            arrangements,
+            if arrangements == 1 { "" } else { "s" }
+        ));
+    }
+    steps.push(format!(
+        "The product over all sections is {}",
+        distinct_arrangements
+    ));
+    Solution::new(
+        distinct_arrangements,
+        format!("{} distinct arrangements", distinct_arrangements),
+    )
+    .with_steps(steps)
+}
+
+pub const META: Meta = Meta {
+    title: "Adapter Array",
+    tags: &[Tag::Graph],
+    strategies: &[
+        "product of exhaustive counts over sections split at gaps of 3",
+        "exhaustive count of every arrangement",
+    ],
+    complexity: "O(n), exponential without sections",
+};
+
+pub fn solve(input: &str, _config: &Config) -> Result<Day, SolveError> {
+    let mut lap = Lap::new();
+    let numbers = parse(input)?;
+    debug!(count = numbers.len(), first = ?numbers.first(), "parsed");
+    let part1 = part1(&numbers);
+    let part1_stats = lap.lap();
+    let part2 = part2(&numbers);
+    let part2_stats = lap.lap();
+    Ok(Day {
+        stats: Parts(part1_stats, part2_stats),
+        ..Day::new(part1, part2)
+    })
+}
+
+/// The answer to a single part, without solving the other
+pub fn solve_part(input: &str, _config: &Config, part: Part) -> Result<Answer, SolveError> {
+    let numbers = parse(input)?;
+    Ok(match part {
+        Part::One => part1(&numbers).answer.into(),
+        Part::Two => part2(&numbers).answer.into(),
+    })
+}
+
+pub fn main(config: &Config) -> Result<Day, SolveError> {
+    solve(&get_string("day10.txt"), config)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::answer::Answer;
+
+    #[test]
+    fn test_main() {
+        let day = main(&Config::default()).unwrap();
+        assert_eq!(day.answers.0, Answer::Int(2592));
+        assert_eq!(day.answers.1, Answer::Int(198428693313536));
+    }
+
+    #[test]
+    fn test_steps() {
+        let numbers = parse("16\n10\n15\n5\n1\n11\n7\n19\n6\n12\n4").unwrap();
+        let solution = part2(&numbers);
+        assert_eq!(solution.answer, 8);
+        assert_eq!(
+            solution.steps,
+            [
+                "Split the chain into 2 sections at adapters 3 jolts from both neighbours",
+                "Section 1: 0..=19 (12 joltages) has 8 arrangements",
+                "Section 2: 19..=22 (2 joltages) has 1 arrangement",
+                "The product over all sections is 8",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_count() {
+        for (name, count) in [
+            (
+                "exhaustive_count",
+                exhaustive_count as fn(Vec<usize>) -> usize,
+            ),
+            ("section_count", section_count),
+        ]
+        .iter()
+        {
+            for (adapters, expected) in &[
+                (vec![0, 1], 1),
+                (vec![0, 2], 1),
+                (vec![0, 3], 1),
+                (vec![1, 2], 1),
+                (vec![3, 6], 1),
+                (vec![0, 1, 2], 2),
+                (vec![0, 1, 3], 2),
+                (vec![0, 2, 3], 2),
+                (vec![0, 1, 2, 3], 4),
+                (vec![0, 4], 0),
+                (vec![0, 3, 6], 1),
+                (vec![0, 1, 3, 6], 2),
+                (vec![0, 3, 4, 7], 1),
+                (vec![0, 1, 3, 4, 6], 5),
+                (vec![0, 1, 4, 5, 6, 7, 10, 11, 12, 15, 16, 19, 22], 8),
+            ] {
+                assert_eq!(
+                    count(adapters.clone()),
+                    *expected,
+                    "{} {:?}",
+                    name,
+                    adapters
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,407 @@
+use std::fmt;
+
+use serde::Deserialize;
+use tracing::{debug, instrument, trace};
+
+use super::get_string;
+use crate::answer::Answer;
+use crate::automaton::{grid_neighbours, Automaton};
+use crate::config::{self, Config};
+use crate::geom::Direction8;
+use crate::grid::{Grid, Pos};
+use crate::memory::Lap;
+use crate::meta::{Meta, Tag};
+use crate::parse::{Cell, ParseError};
+use crate::repl::{arg, ReplError, Session};
+use crate::{Day, Part, Parts, Solution, SolveError};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum GridState {
+    Floor,
+    Empty,
+    Occupied,
+}
+
+impl fmt::Display for GridState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::Floor => ".",
+                Self::Empty => "L",
+                Self::Occupied => "#",
+            }
+        )
+    }
+}
+
+impl Cell for GridState {
+    const CHARS: &'static str = ".L#";
+
+    fn from_char(c: char) -> Option<Self> {
+        match c {
+            '.' => Some(Self::Floor),
+            'L' => Some(Self::Empty),
+            '#' => Some(Self::Occupied),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Params {
+    /// Occupied adjacent seats that make a person leave
+    pub part1_threshold: usize,
+    /// Occupied visible seats that make a person leave
+    pub part2_threshold: usize,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Params {
+            part1_threshold: 4,
+            part2_threshold: 5,
+        }
+    }
+}
+
+impl config::Params for Params {
+    fn validate(&self) -> Result<(), (&'static str, String)> {
+        // A seat has at most one neighbour in each of the 8 directions
+        let reachable = |threshold| match threshold {
+            1..=8 => Ok(()),
+            _ => Err(format!("{} occupied seats not in 1..=8", threshold)),
+        };
+        reachable(self.part1_threshold).map_err(|e| ("part1_threshold", e))?;
+        reachable(self.part2_threshold).map_err(|e| ("part2_threshold", e))
+    }
+}
+
+pub type GridMap = Grid<GridState>;
+
+/// Generations after which seating that still changes is taken to never settle
+const MAX_GENERATIONS: usize = 1_000;
+
+impl GridMap {
+    pub fn count_occupied(&self) -> usize {
+        self.iter()
+            .filter(|(_, v)| matches!(v, GridState::Occupied))
+            .count()
+    }
+
+    /// Applies the seating rules `generations` times, or until nothing changes
+    ///
+    /// Returns the occupied seats after each generation, `None` when the seats
+    /// still change after [`MAX_GENERATIONS`].
+    fn evolve<N>(
+        &mut self,
+        threshold: usize,
+        neighbourhood: N,
+        generations: Option<usize>,
+    ) -> Option<Vec<usize>>
+    where
+        N: Fn(&GridMap, &Pos) -> Vec<Pos> + Sync,
+    {
+        let rule = |grid_pos: &GridState, neighbours: &[GridState]| {
+            let occupied_count = neighbours
+                .iter()
+                .filter(|v| matches!(v, GridState::Occupied))
+                .count();
+            match grid_pos {
+                GridState::Empty if occupied_count == 0 => GridState::Occupied,
+                GridState::Occupied if occupied_count >= threshold => GridState::Empty,
+                _ => grid_pos.clone(),
+            }
+        };
+        let mut occupied = vec![];
+        let mut automaton = Automaton::new(self.clone(), neighbourhood, rule).on_generation(
+            |generation, grid_map: &GridMap| {
+                let count = grid_map.count_occupied();
+                debug!(generation, occupied = count, "generation");
+                trace!("\n{}", grid_map);
+                occupied.push(count);
+            },
+        );
+        let settled = match generations {
+            Some(generations) => {
+                automaton.run(generations);
+                true
+            }
+            None => automaton.run_until_stable(MAX_GENERATIONS).is_some(),
+        };
+        *self = automaton.into_current();
+        Some(occupied).filter(|_| settled)
+    }
+
+    pub fn solve_part1(&mut self, threshold: usize) -> Option<Vec<usize>> {
+        self.evolve(threshold, grid_neighbours, None)
+    }
+
+    pub fn solve_part2(&mut self, threshold: usize) -> Option<Vec<usize>> {
+        self.evolve(threshold, visible_seats, None)
+    }
+}
+
+/// First seat seen in each direction
+fn visible_seats(grid_map: &GridMap, &pos: &Pos) -> Vec<Pos> {
+    Direction8::ALL
+        .iter()
+        .filter_map(|&direction| {
+            grid_map
+                .ray(pos, direction)
+                .find(|(_, v)| !matches!(v, GridState::Floor))
+                .map(|(pos, _)| pos)
+        })
+        .collect()
+}
+
+#[instrument(skip_all)]
+pub fn parse(input: &str) -> Result<GridMap, ParseError> {
+    input.parse()
+}
+
+/// The rules, then the occupied seats per generation until the last one changes nothing
+///
+/// Seating that never settles answers `none`.
+fn solution(
+    grid_map: &GridMap,
+    generations: Option<Vec<usize>>,
+    rule: &str,
+    threshold: usize,
+    neighbours: &str,
+) -> Solution<Answer> {
+    let rule = format!(
+        "{}, an occupied one empties with {} or more occupied {}",
+        rule, threshold, neighbours
+    );
+    let generations = match generations {
+        Some(generations) => generations,
+        None => {
+            let none = format!("Still changing after {} generations", MAX_GENERATIONS);
+            return Solution::new(Answer::from("none"), none.clone()).with_steps(vec![rule, none]);
+        }
+    };
+    let mut steps = vec![rule];
+    steps.extend(
+        generations
+            .iter()
+            .enumerate()
+            .map(|(idx, occupied)| format!("Generation {}: {} occupied", idx + 1, occupied)),
+    );
+    steps.push(format!(
+        "Stable after {} generations",
+        generations.len().saturating_sub(1)
+    ));
+    let occupied = grid_map.count_occupied();
+    Solution::new(Answer::from(occupied), format!("{} occupied.", occupied)).with_steps(steps)
+}
+
+#[instrument(skip_all)]
+pub fn part1(init_grid_map: &GridMap, params: &Params) -> Solution<Answer> {
+    let mut grid_map = init_grid_map.clone();
+    let generations = grid_map.solve_part1(params.part1_threshold);
+    solution(
+        &grid_map,
+        generations,
+        "An empty seat fills with no occupied neighbour",
+        params.part1_threshold,
+        "neighbours",
+    )
+}
+
+#[instrument(skip_all)]
+pub fn part2(init_grid_map: &GridMap, params: &Params) -> Solution<Answer> {
+    let mut grid_map = init_grid_map.clone();
+    let generations = grid_map.solve_part2(params.part2_threshold);
+    solution(
+        &grid_map,
+        generations,
+        "An empty seat fills when it sees no occupied seat",
+        params.part2_threshold,
+        "visible seats",
+    )
+}
+
+pub const META: Meta = Meta {
+    title: "Seating System",
+    tags: &[Tag::Grid],
+    strategies: &["cellular automaton run until stable, over adjacent or visible seats"],
+    complexity: "O(g·r·c)",
+};
+
+pub fn solve(input: &str, config: &Config) -> Result<Day, SolveError> {
+    let mut lap = Lap::new();
+    let params: Params = config.checked("day11")?;
+    let init_grid_map = parse(input)?;
+    trace!("initial seats\n{}", init_grid_map);
+    let part1 = part1(&init_grid_map, &params);
+    let part1_stats = lap.lap();
+    let part2 = part2(&init_grid_map, &params);
+    let part2_stats = lap.lap();
+    Ok(Day {
+        stats: Parts(part1_stats, part2_stats),
+        ..Day::new(part1, part2)
+    })
+}
+
+/// The answer to a single part, without solving the other
+pub fn solve_part(input: &str, config: &Config, part: Part) -> Result<Answer, SolveError> {
+    let params: Params = config.checked("day11")?;
+    let init_grid_map = parse(input)?;
+    Ok(match part {
+        Part::One => part1(&init_grid_map, &params).answer,
+        Part::Two => part2(&init_grid_map, &params).answer,
+    })
+}
+
+pub fn main(config: &Config) -> Result<Day, SolveError> {
+    solve(&get_string("day11.txt"), config)
+}
+
+/// Seating after any number of generations
+pub struct Repl {
+    init_grid_map: GridMap,
+    params: Params,
+}
+
+impl Repl {
+    pub fn open(input: &str, config: &Config) -> Box<dyn Session> {
+        Box::new(Repl {
+            init_grid_map: parse(input).unwrap_or_else(|e| panic!("{}", e)),
+            params: config.checked("day11").unwrap_or_else(|e| panic!("{}", e)),
+        })
+    }
+}
+
+impl Session for Repl {
+    fn commands(&self) -> &'static [(&'static str, &'static str)] {
+        &[(
+            "generations <n> [visible]",
+            "seats after n generations, by adjacent or visible seats",
+        )]
+    }
+
+    fn eval(&mut self, command: &str, args: &[&str]) -> Result<String, ReplError> {
+        if command != "generations" {
+            return Err(ReplError::UnknownCommand(command.to_string()));
+        }
+        let generations = arg(args, 0)?;
+        let mut grid_map = self.init_grid_map.clone();
+        match args.get(1..) {
+            Some([]) => grid_map.evolve(
+                self.params.part1_threshold,
+                grid_neighbours,
+                Some(generations),
+            ),
+            Some(["visible"]) => grid_map.evolve(
+                self.params.part2_threshold,
+                visible_seats,
+                Some(generations),
+            ),
+            _ => return Err(ReplError::Usage),
+        };
+        Ok(format!(
+            "{} occupied after {} generations.\n{}",
+            grid_map.count_occupied(),
+            generations,
+            grid_map
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+L.LL.LL.LL
+LLLLLLL.LL
+L.L.L..L..
+LLLL.LL.LL
+L.LL.LL.LL
+L.LLLLL.LL
+..L.L.....
+LLLLLLLLLL
+L.LLLLLL.L
+L.LLLLL.LL";
+
+    #[test]
+    fn test_params() {
+        let mut config = Config::default();
+        config.set("day11.part1_threshold=0").unwrap();
+        assert_eq!(
+            config.checked::<Params>("day11").unwrap_err().to_string(),
+            "invalid `day11.part1_threshold`: 0 occupied seats not in 1..=8"
+        );
+        config.set("day11.part1_threshold=8").unwrap();
+        config.set("day11.part2_threshold=9").unwrap();
+        assert!(matches!(
+            config.checked::<Params>("day11"),
+            Err(config::ConfigError::Value(key, _)) if key == "day11.part2_threshold"
+        ));
+    }
+
+    #[test]
+    fn test_part2() {
+        let mut grid_map: GridMap = EXAMPLE.parse().unwrap();
+        grid_map.solve_part2(5);
+        assert_eq!(grid_map.count_occupied(), 26);
+    }
+
+    #[test]
+    fn test_part1() {
+        let mut grid_map: GridMap = EXAMPLE.parse().unwrap();
+        grid_map.solve_part1(4);
+        assert_eq!(grid_map.count_occupied(), 37);
+    }
+
+    #[test]
+    fn test_never_settles() {
+        // Two seats fill together and empty together
+        let grid_map: GridMap = "LL".parse().unwrap();
+        let params = Params {
+            part1_threshold: 1,
+            ..Params::default()
+        };
+        let solution = part1(&grid_map, &params);
+        assert_eq!(
+            (solution.answer, solution.display.as_str()),
+            (
+                Answer::from("none"),
+                "Still changing after 1000 generations"
+            )
+        );
+        assert_eq!(part2(&grid_map, &params).answer, Answer::Int(2));
+    }
+
+    #[test]
+    fn test_repl() {
+        let mut repl = Repl::open(EXAMPLE, &Config::default());
+        assert_eq!(
+            repl.eval("generations", &["2"]).unwrap(),
+            "\
+20 occupied after 2 generations.
+#.LL.L#.##
+#LLLLLL.L#
+L.L.L..L..
+#LLL.LL.L#
+#.LL.LL.LL
+#.LLLL#.##
+..L.L.....
+#LLLLLLLL#
+#.LLLLLL.L
+#.#LLLL.##
+"
+        );
+        assert!(repl
+            .eval("generations", &["100", "visible"])
+            .unwrap()
+            .starts_with("26 occupied"));
+        assert_eq!(
+            repl.eval("generations", &["1", "diagonal"]),
+            Err(ReplError::Usage)
+        );
+    }
+}
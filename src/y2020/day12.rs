@@ -0,0 +1,302 @@
+use std::fmt;
+use std::str::FromStr;
+
+use tracing::{instrument, trace};
+
+use super::get_string;
+use crate::answer::Answer;
+use crate::config::Config;
+use crate::geom::{Compass, Vec2};
+use crate::memory::Lap;
+use crate::meta::Meta;
+use crate::parse::{self, lines, Spanned};
+use crate::{Day, Part, Parts, Solution, SolveError};
+
+#[derive(Debug, Clone, Copy)]
+pub enum InstructionAction {
+    North,
+    South,
+    East,
+    West,
+    Left,
+    Right,
+    Forward,
+}
+
+impl fmt::Display for InstructionAction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::North => "N",
+                Self::South => "S",
+                Self::East => "E",
+                Self::West => "W",
+                Self::Left => "L",
+                Self::Right => "R",
+                Self::Forward => "F",
+            }
+        )
+    }
+}
+
+#[derive(Debug)]
+pub struct ParseError;
+
+impl FromStr for InstructionAction {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "N" => Ok(Self::North),
+            "S" => Ok(Self::South),
+            "E" => Ok(Self::East),
+            "W" => Ok(Self::West),
+            "L" => Ok(Self::Left),
+            "R" => Ok(Self::Right),
+            "F" => Ok(Self::Forward),
+            _ => Err(ParseError),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Instruction {
+    pub action: InstructionAction,
+    pub val: u32,
+}
+
+impl fmt::Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}", self.action, self.val)
+    }
+}
+
+impl Instruction {
+    pub fn parse(line: Spanned<'_>) -> Result<Self, parse::ParseError> {
+        let hint = "expected an action N/S/E/W/L/R/F and a number, like `F10`";
+        let (c, action) = line
+            .chars()
+            .next()
+            .ok_or_else(|| line.error("empty instruction").with_hint(hint))?;
+        let action = action.value.parse().map_err(|_| {
+            action
+                .error(format!("unknown action `{}`", c))
+                .with_hint(hint)
+        })?;
+        let val = line.slice(&line.value[c.len_utf8()..]);
+        let instruction = Self {
+            action,
+            val: val.parse().map_err(|e| e.with_hint(hint))?,
+        };
+        if matches!(
+            instruction.action,
+            InstructionAction::Left | InstructionAction::Right
+        ) && !matches!(instruction.val, 90 | 180 | 270)
+        {
+            return Err(val
+                .error(format!("cannot turn by {} degrees", instruction.val))
+                .with_hint("turns must be 90, 180 or 270 degrees"));
+        }
+        Ok(instruction)
+    }
+}
+
+#[derive(Clone)]
+pub struct Ferry {
+    pub facing: Compass,
+    pub position: Vec2<i64>,
+    pub waypoint: Vec2<i64>,
+}
+
+impl fmt::Display for Ferry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} ({},{}) ({}, {})",
+            self.facing, self.position.x, self.position.y, self.waypoint.x, self.waypoint.y
+        )
+    }
+}
+
+impl Default for Ferry {
+    fn default() -> Self {
+        Ferry {
+            facing: Compass::East,
+            position: Vec2::new(0, 0),
+            waypoint: Vec2::new(10, 1),
+        }
+    }
+}
+
+impl InstructionAction {
+    fn compass(self) -> Option<Compass> {
+        match self {
+            Self::North => Some(Compass::North),
+            Self::South => Some(Compass::South),
+            Self::East => Some(Compass::East),
+            Self::West => Some(Compass::West),
+            _ => None,
+        }
+    }
+}
+
+impl Instruction {
+    /// Clockwise quarter turns of an `L` or `R` instruction, whole turns are checked by `parse`
+    fn quarter_turns(&self) -> i32 {
+        let turns = (self.val / 90) as i32;
+        match self.action {
+            InstructionAction::Right => turns,
+            InstructionAction::Left => -turns,
+            _ => panic!("{}", self),
+        }
+    }
+}
+
+impl Ferry {
+    pub fn manhattan_distance(&self) -> i64 {
+        self.position.manhattan()
+    }
+
+    pub fn move_part1(&mut self, instruction: &Instruction) {
+        let val = i64::from(instruction.val);
+        match instruction.action {
+            InstructionAction::Left | InstructionAction::Right => {
+                self.facing = self.facing.turn(instruction.quarter_turns())
+            }
+            InstructionAction::Forward => self.position += self.facing.unit() * val,
+            action => self.position += action.compass().unwrap().unit() * val,
+        }
+    }
+
+    pub fn move_part2(&mut self, instruction: &Instruction) {
+        let val = i64::from(instruction.val);
+        match instruction.action {
+            InstructionAction::Left | InstructionAction::Right => {
+                // Vec2 turns counterclockwise
+                self.waypoint = self.waypoint.rotate(-instruction.quarter_turns())
+            }
+            InstructionAction::Forward => self.position += self.waypoint * val,
+            action => self.waypoint += action.compass().unwrap().unit() * val,
+        }
+    }
+}
+
+#[instrument(skip_all)]
+pub fn parse(input: &str) -> Result<Vec<Instruction>, parse::ParseError> {
+    lines(input).map(Instruction::parse).collect()
+}
+
+#[instrument(skip_all)]
+pub fn part1(instructions: &[Instruction]) -> Solution<i64> {
+    let mut ferry = Ferry::default();
+    for instruction in instructions.iter() {
+        ferry.move_part1(instruction);
+        trace!(%instruction, %ferry, "moved");
+    }
+    let distance = ferry.manhattan_distance();
+    let steps = vec![
+        format!(
+            "Followed {} instructions, N/S/E/W and F moving the ship, L/R turning it",
+            instructions.len()
+        ),
+        format!(
+            "The ship ends at {} facing {}",
+            ferry.position, ferry.facing
+        ),
+        format!(
+            "|{}| + |{}| = {}",
+            ferry.position.x, ferry.position.y, distance
+        ),
+    ];
+    Solution::new(distance, format!("Manhattan distance: {}", distance)).with_steps(steps)
+}
+
+#[instrument(skip_all)]
+pub fn part2(instructions: &[Instruction]) -> Solution<i64> {
+    let mut ferry = Ferry::default();
+    for instruction in instructions.iter() {
+        ferry.move_part2(instruction);
+        trace!(%instruction, %ferry, "moved");
+    }
+    let distance = ferry.manhattan_distance();
+    let steps = vec![
+        format!(
+            "Followed {} instructions, N/S/E/W moving the waypoint, L/R turning it \
+             around the ship and F moving the ship toward it",
+            instructions.len()
+        ),
+        format!(
+            "The ship ends at {} with the waypoint at {}",
+            ferry.position, ferry.waypoint
+        ),
+        format!(
+            "|{}| + |{}| = {}",
+            ferry.position.x, ferry.position.y, distance
+        ),
+    ];
+    Solution::new(distance, format!("Manhattan distance: {}", distance)).with_steps(steps)
+}
+
+pub const META: Meta = Meta {
+    title: "Rain Risk",
+    tags: &[],
+    strategies: &["ship heading for part 1, rotating waypoint for part 2"],
+    complexity: "O(n)",
+};
+
+pub fn solve(input: &str, _config: &Config) -> Result<Day, SolveError> {
+    let mut lap = Lap::new();
+    let instructions = parse(input)?;
+    let part1 = part1(&instructions);
+    let part1_stats = lap.lap();
+    let part2 = part2(&instructions);
+    let part2_stats = lap.lap();
+    Ok(Day {
+        stats: Parts(part1_stats, part2_stats),
+        ..Day::new(part1, part2)
+    })
+}
+
+/// The answer to a single part, without solving the other
+pub fn solve_part(input: &str, _config: &Config, part: Part) -> Result<Answer, SolveError> {
+    let instructions = parse(input)?;
+    Ok(match part {
+        Part::One => part1(&instructions).answer.into(),
+        Part::Two => part2(&instructions).answer.into(),
+    })
+}
+
+pub fn main(config: &Config) -> Result<Day, SolveError> {
+    solve(&get_string("day12.txt"), config)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::answer::Answer;
+
+    #[test]
+    fn test_main() {
+        let day = main(&Config::default()).unwrap();
+        assert_eq!(day.answers.0, Answer::Int(858));
+        assert_eq!(day.answers.1, Answer::Int(39140));
+    }
+
+    #[test]
+    fn test_parse() {
+        let instructions = parse("F10\nR90").unwrap();
+        assert_eq!(instructions[1].to_string(), "R90");
+        let error = parse("F10\nQ90").unwrap_err();
+        assert_eq!((error.span.line, error.span.col), (2, 1));
+        let error = parse("F1x").unwrap_err();
+        assert_eq!((error.span.col, error.span.len), (2, 2));
+        let error = parse("F10\nL45").unwrap_err();
+        assert_eq!(
+            (error.span.line, error.span.col, error.message.as_str()),
+            (2, 2, "cannot turn by 45 degrees")
+        );
+        assert!(parse("F45\nL270").is_ok());
+    }
+}
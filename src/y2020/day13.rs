@@ -0,0 +1,446 @@
+use std::collections::VecDeque;
+use std::convert::TryFrom;
+use std::fmt;
+use std::str::FromStr;
+use std::thread;
+
+use itertools::Itertools;
+use tracing::{debug, instrument, trace};
+
+use super::get_string;
+use crate::answer::Answer;
+use crate::config::Config;
+use crate::memory::Lap;
+use crate::meta::{Meta, Tag};
+use crate::numtheory::crt;
+use crate::parse::{self, comma_list, lines};
+use crate::repl::{arg, ReplError, Session};
+use crate::{Day, Part, Parts, Solution, SolveError};
+
+#[derive(Debug, Clone)]
+pub enum Bus {
+    Active(u64),
+    OutOfService,
+}
+
+impl fmt::Display for Bus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::Active(v) => v.to_string(),
+                Self::OutOfService => "x".to_string(),
+            }
+        )
+    }
+}
+
+#[derive(Debug)]
+pub struct ParseError;
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "expected a bus id or `x`")
+    }
+}
+
+impl FromStr for Bus {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "x" => Ok(Self::OutOfService),
+            vs => vs.parse().map(Self::Active).map_err(|_| ParseError),
+        }
+    }
+}
+
+pub fn find_time_loop(busses: &[Bus], start: u64) -> u64 {
+    let delta: u64 = match busses.first() {
+        Some(Bus::Active(v)) => *v,
+        _ => panic!(),
+    };
+    let mut timestamp: u64 = start;
+    loop {
+        if busses.iter().enumerate().all(|(idx, bus)| match bus {
+            Bus::Active(n) if !(timestamp + idx as u64).is_multiple_of(*n) => false,
+            Bus::Active(n) if (timestamp + idx as u64).is_multiple_of(*n) => true,
+            Bus::OutOfService => true,
+            _ => panic!(),
+        }) {
+            break;
+        }
+        timestamp += delta;
+    }
+    timestamp
+}
+
+fn find_largest(busses: &[Bus]) -> (usize, u64) {
+    match busses.iter().enumerate().max_by_key(|(_, b)| match b {
+        Bus::Active(v) => *v,
+        Bus::OutOfService => 0,
+    }) {
+        Some((idx, Bus::Active(v))) => (idx, *v),
+        _ => panic!(),
+    }
+}
+
+pub fn find_time_loop_max(busses: &[Bus], start: u64) -> u64 {
+    let (idx, delta) = find_largest(busses);
+    let mut timestamp: u64 = start + delta - u64::try_from(idx).unwrap();
+    debug!(timestamp, delta, "starting brute force");
+    loop {
+        if busses.iter().enumerate().all(|(idx, bus)| match bus {
+            Bus::Active(n) if !(timestamp + idx as u64).is_multiple_of(*n) => false,
+            Bus::Active(n) if (timestamp + idx as u64).is_multiple_of(*n) => true,
+            Bus::OutOfService => true,
+            _ => panic!(),
+        }) {
+            break;
+        }
+        timestamp += delta;
+        if timestamp.is_multiple_of(1_000_000_000) {
+            trace!(timestamp, "searching");
+        }
+    }
+    timestamp
+}
+
+pub fn check_times_start_end(busses: &[Bus], start: u64, end: u64, delta: u64) -> Option<u64> {
+    let mut timestamp: u64 = start;
+    while timestamp < end {
+        if busses.iter().enumerate().all(|(idx, bus)| match bus {
+            Bus::Active(n) if !(timestamp + idx as u64).is_multiple_of(*n) => false,
+            Bus::Active(n) if (timestamp + idx as u64).is_multiple_of(*n) => true,
+            Bus::OutOfService => true,
+            _ => panic!(),
+        }) {
+            return Some(timestamp);
+        }
+        timestamp += delta;
+    }
+    None
+}
+
+pub fn find_time_thread(busses: &[Bus], start: u64) -> u64 {
+    const ITERS_PER_THREAD: u64 = 100_000_000;
+    const THREAD_COUNT: u64 = 16;
+    let (idx, delta) = find_largest(busses);
+    let offset_per_thread = delta * ITERS_PER_THREAD;
+    let mut timestamp: u64 = start + delta - u64::try_from(idx).unwrap();
+    debug!(timestamp, delta, "starting");
+    let mut threads: VecDeque<_> = (0..THREAD_COUNT)
+        .map(|idx| {
+            let start = timestamp + idx * offset_per_thread;
+            let end = start + offset_per_thread;
+            let busses: Vec<Bus> = busses.to_vec();
+            trace!(start, end, "spawn thread");
+            thread::spawn(move || check_times_start_end(&busses, start, end, delta))
+        })
+        .collect();
+    timestamp += THREAD_COUNT * offset_per_thread;
+    loop {
+        let thread = threads.pop_front().unwrap();
+        match thread.join().unwrap() {
+            Some(t) => break t,
+            None => {
+                let end = timestamp + offset_per_thread;
+                let busses: Vec<Bus> = busses.to_vec();
+                trace!(start = timestamp, end, "spawn thread");
+                threads.push_back(thread::spawn(move || {
+                    check_times_start_end(&busses, timestamp, end, delta)
+                }));
+                timestamp = end;
+            }
+        }
+    }
+}
+
+/// `timestamp ≡ -idx (mod bus)` for every bus in service
+fn congruences(busses: &[Bus]) -> Vec<(i128, i128)> {
+    busses
+        .iter()
+        .enumerate()
+        .filter_map(|(idx, bus)| match bus {
+            Bus::Active(b) => Some((-i128::try_from(idx).unwrap(), i128::from(*b))),
+            Bus::OutOfService => None,
+        })
+        .collect()
+}
+
+pub fn chinese_remainder_busses(busses: &[Bus], _start: u64) -> u64 {
+    let congruences = congruences(busses);
+    debug!(?congruences, "timestamp ≡ r (mod m)");
+    let (timestamp, period) = crt(&congruences).expect("solvable, checked by `parse`");
+    debug!(timestamp, period, "combined");
+    u64::try_from(timestamp).unwrap()
+}
+
+#[derive(Debug)]
+pub struct Notes {
+    pub target: u64,
+    pub busses: Vec<Bus>,
+}
+
+#[instrument(skip_all)]
+pub fn parse(input: &str) -> Result<Notes, parse::ParseError> {
+    let hint = "expected the earliest departure, then bus ids or `x` separated by commas";
+    let missing =
+        |what: &str| parse::ParseError::at_end(input, format!("missing {}", what)).with_hint(hint);
+    let mut lines = lines(input);
+    let target = lines
+        .next()
+        .ok_or_else(|| missing("the earliest departure"))?;
+    let busses = lines.next().ok_or_else(|| missing("the bus ids"))?;
+    let notes = Notes {
+        target: target.parse().map_err(|e| e.with_hint(hint))?,
+        busses: comma_list(busses).map_err(|e| e.with_hint(hint))?,
+    };
+    if let Some(zero) = busses
+        .split(',')
+        .map(|bus| bus.trim())
+        .find(|bus| bus.value.parse::<u64>() == Ok(0))
+    {
+        return Err(zero
+            .error("bus 0 never leaves")
+            .with_hint("bus ids are at least 1"));
+    }
+    if !notes.busses.iter().any(|bus| matches!(bus, Bus::Active(_))) {
+        return Err(busses
+            .error("no bus in service")
+            .with_hint("expected at least one bus id besides the `x`s"));
+    }
+    let earliest = crt(&congruences(&notes.busses)).and_then(|(t, _)| u64::try_from(t).ok());
+    if earliest.is_none() {
+        return Err(busses
+            .error("no timestamp fits every bus")
+            .with_hint("busses sharing a factor must leave at offsets that agree on it"));
+    }
+    Ok(notes)
+}
+
+#[instrument(skip_all)]
+pub fn part1(notes: &Notes) -> Solution<u64> {
+    let target = notes.target;
+    let mut delays: Vec<(u64, u64)> = notes
+        .busses
+        .iter()
+        .filter_map(|b| match b {
+            Bus::Active(v) => Some((*v, (v - target % v))),
+            Bus::OutOfService => None,
+        })
+        .collect();
+    debug!(?delays, "minutes to wait per bus");
+    delays.sort_unstable_by(|(_lhs_busid, lhs_delay), (_rhs_busid, rhs_delay)| {
+        lhs_delay.partial_cmp(rhs_delay).unwrap()
+    });
+    let (busid, delay) = delays.first().unwrap();
+    let mut steps: Vec<String> = delays
+        .iter()
+        .map(|(busid, delay)| format!("Bus {} comes {} minutes after {}", busid, delay, target))
+        .collect();
+    steps.push(format!(
+        "Bus {} comes first, {} × {} = {}",
+        busid,
+        busid,
+        delay,
+        busid * delay
+    ));
+    Solution::new(
+        busid * delay,
+        format!(
+            "{} = Bus {} × {} minutes of delay",
+            busid * delay,
+            busid,
+            delay
+        ),
+    )
+    .with_steps(steps)
+}
+
+#[instrument(skip_all)]
+pub fn part2(notes: &Notes) -> Solution<u64> {
+    let mut steps = vec![];
+    // Folded one bus at a time, as `crt` does, to show each combined congruence
+    let (time, _period) = congruences(&notes.busses).into_iter().fold(
+        (0, 1),
+        |(timestamp, period), (offset, bus)| {
+            let combined =
+                crt(&[(timestamp, period), (offset, bus)]).expect("solvable, checked by `parse`");
+            steps.push(format!(
+                "Bus {} leaves {} minutes later: t ≡ {} (mod {}), so t ≡ {} (mod {})",
+                bus,
+                -offset,
+                offset.rem_euclid(bus),
+                bus,
+                combined.0,
+                combined.1
+            ));
+            combined
+        },
+    );
+    steps.push(format!("The smallest such t is {}", time));
+    let time = u64::try_from(time).unwrap();
+    Solution::new(time, format!("{} = is the earliest timestamp", time)).with_steps(steps)
+}
+
+pub const META: Meta = Meta {
+    title: "Shuttle Search",
+    tags: &[Tag::NumberTheory],
+    strategies: &[
+        "chinese remainder theorem",
+        "brute force stepping by the first bus",
+        "brute force stepping by the largest bus",
+        "brute force split over threads",
+    ],
+    complexity: "O(b²) with the CRT",
+};
+
+pub fn solve(input: &str, _config: &Config) -> Result<Day, SolveError> {
+    let mut lap = Lap::new();
+    let notes = parse(input)?;
+    debug!(
+        target = notes.target,
+        busses = %notes.busses.iter().join(","),
+        "parsed"
+    );
+    let part1 = part1(&notes);
+    let part1_stats = lap.lap();
+    let part2 = part2(&notes);
+    let part2_stats = lap.lap();
+    Ok(Day {
+        stats: Parts(part1_stats, part2_stats),
+        ..Day::new(part1, part2)
+    })
+}
+
+/// The answer to a single part, without solving the other
+pub fn solve_part(input: &str, _config: &Config, part: Part) -> Result<Answer, SolveError> {
+    let notes = parse(input)?;
+    Ok(match part {
+        Part::One => part1(&notes).answer.into(),
+        Part::Two => part2(&notes).answer.into(),
+    })
+}
+
+pub fn main(config: &Config) -> Result<Day, SolveError> {
+    solve(&get_string("day13.txt"), config)
+}
+
+/// Departures from any time
+pub struct Repl {
+    notes: Notes,
+}
+
+impl Repl {
+    pub fn open(input: &str, _config: &Config) -> Box<dyn Session> {
+        Box::new(Repl {
+            notes: parse(input).unwrap_or_else(|e| panic!("{}", e)),
+        })
+    }
+}
+
+impl Session for Repl {
+    fn commands(&self) -> &'static [(&'static str, &'static str)] {
+        &[("next <t>", "first bus leaving after time t")]
+    }
+
+    fn eval(&mut self, command: &str, args: &[&str]) -> Result<String, ReplError> {
+        match command {
+            "next" => Ok(part1(&Notes {
+                target: arg(args, 0)?,
+                busses: self.notes.busses.clone(),
+            })
+            .display),
+            _ => Err(ReplError::UnknownCommand(command.to_string())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::answer::Answer;
+
+    #[test]
+    fn test_main() {
+        let day = main(&Config::default()).unwrap();
+        assert_eq!(day.answers.0, Answer::Int(153));
+        assert_eq!(day.answers.1, Answer::Int(471793476184394));
+    }
+
+    #[test]
+    fn test_repl() {
+        let mut repl = Repl::open("939\n7,13,x,x,59,x,31,19", &Config::default());
+        assert_eq!(
+            repl.eval("next", &["939"]),
+            Ok("295 = Bus 59 × 5 minutes of delay".to_string())
+        );
+        assert_eq!(
+            repl.eval("next", &["944"]),
+            Ok("7 = Bus 7 × 1 minutes of delay".to_string())
+        );
+        assert_eq!(repl.eval("next", &[]), Err(ReplError::Usage));
+    }
+
+    #[test]
+    fn test_steps() {
+        let solution = part2(&parse("939\n17,x,13,19").unwrap());
+        assert_eq!(solution.answer, 3417);
+        assert_eq!(
+            solution.steps,
+            [
+                "Bus 17 leaves 0 minutes later: t ≡ 0 (mod 17), so t ≡ 0 (mod 17)",
+                "Bus 13 leaves 2 minutes later: t ≡ 11 (mod 13), so t ≡ 102 (mod 221)",
+                "Bus 19 leaves 3 minutes later: t ≡ 16 (mod 19), so t ≡ 3417 (mod 4199)",
+                "The smallest such t is 3417",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse() {
+        let error = parse("939\n").unwrap_err();
+        assert_eq!(
+            (error.span.line, error.message.as_str()),
+            (2, "missing the bus ids")
+        );
+        let error = parse("939\n7,13,y").unwrap_err();
+        assert_eq!((error.span.line, error.span.col), (2, 6));
+        let error = parse("939\nx,x").unwrap_err();
+        assert_eq!(
+            (error.span.line, error.message.as_str()),
+            (2, "no bus in service")
+        );
+        let error = parse("939\n7,0").unwrap_err();
+        assert_eq!(
+            (error.span.col, error.message.as_str()),
+            (3, "bus 0 never leaves")
+        );
+        let error = parse("939\n4,6").unwrap_err();
+        assert_eq!(error.message, "no timestamp fits every bus");
+        assert!(parse("939\n4,x,6").is_ok());
+    }
+
+    #[test]
+    fn test_find_time() {
+        let busses: Vec<Bus> = "7,13,x,x,59,x,31,19"
+            .split(",")
+            .map(|n| n.parse().unwrap())
+            .collect();
+        for (name, find_time) in [
+            (
+                "find_time_loop",
+                find_time_loop as for<'r> fn(&'r [Bus], u64) -> u64,
+            ),
+            ("find_time_loop_max", find_time_loop_max),
+            ("find_time_thread", find_time_thread),
+            ("euclidian_busses", chinese_remainder_busses),
+        ]
+        .iter()
+        {
+            assert_eq!(find_time(&busses, 0), 1068781, "{}", name);
+        }
+    }
+}
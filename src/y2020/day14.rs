@@ -0,0 +1,352 @@
+use itertools::Itertools;
+use std::collections::HashMap;
+use tracing::{debug, instrument, trace};
+
+use super::get_string;
+use crate::answer::Answer;
+use crate::config::Config;
+use crate::memory::Lap;
+use crate::meta::{Meta, Tag};
+use crate::parse::{lines, Spanned};
+use crate::{Day, Part, Parts, Solution, SolveError};
+
+const BITS: usize = 36;
+type M = u64;
+
+#[derive(Debug)]
+pub struct MemSet {
+    addr: M,
+    val: M,
+}
+
+impl std::fmt::Display for MemSet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "mem[{}] = {}", self.addr, self.val)
+    }
+}
+
+#[derive(Debug)]
+pub enum MaskVal {
+    V0,
+    V1,
+    X,
+}
+
+impl std::fmt::Display for MaskVal {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::V0 => "0",
+                Self::V1 => "1",
+                Self::X => "X",
+            }
+        )
+    }
+}
+
+#[derive(Debug)]
+pub struct ParseError;
+
+impl std::str::FromStr for MaskVal {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "0" => Ok(Self::V0),
+            "1" => Ok(Self::V1),
+            "X" => Ok(Self::X),
+            _ => Err(ParseError),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct MaskBlock {
+    mask: Vec<MaskVal>,
+    writes: Vec<MemSet>,
+}
+
+impl std::fmt::Display for MaskBlock {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "mask = {}",
+            self.mask
+                .iter()
+                .map(|m| m.to_string())
+                .collect::<Vec<String>>()
+                .join("")
+        )?;
+        for write in &self.writes {
+            writeln!(f, "{}", write)?;
+        }
+        Ok(())
+    }
+}
+
+pub enum Mask {
+    Or(M),
+    And(M),
+    Floating(M),
+}
+
+impl MaskBlock {
+    fn get_val_masks(&self) -> Vec<Mask> {
+        self.mask
+            .iter()
+            .rev()
+            .enumerate()
+            .filter_map(|(idx, m)| match m {
+                MaskVal::X => None,
+                MaskVal::V0 => Some(Mask::And(!(0b1 << idx))),
+                MaskVal::V1 => Some(Mask::Or(0b1 << idx)),
+            })
+            .collect()
+    }
+    fn get_addr_masks(&self) -> Vec<Mask> {
+        self.mask
+            .iter()
+            .rev()
+            .enumerate()
+            .filter_map(|(idx, m)| match m {
+                MaskVal::X => Some(Mask::Floating(0b1 << idx)),
+                MaskVal::V0 => None,
+                MaskVal::V1 => Some(Mask::Or(0b1 << idx)),
+            })
+            .collect()
+    }
+}
+
+impl MemSet {
+    fn get_val(&self, masks: &[Mask]) -> M {
+        masks.iter().fold(self.val, |acc, m| match m {
+            Mask::Or(m) => acc | m,
+            Mask::And(m) => acc & m,
+            _ => panic!(),
+        })
+    }
+    fn get_addr(&self, masks: &[Mask]) -> Vec<M> {
+        let addr = masks.iter().fold(self.addr, |acc, m| match m {
+            Mask::Or(m) => acc | m,
+            Mask::And(m) => acc & m,
+            Mask::Floating(_m) => acc,
+        });
+        masks
+            .iter()
+            .filter_map(|m| match m {
+                Mask::Floating(m) => Some(vec![0b0, *m].into_iter()),
+                _ => None,
+            })
+            .multi_cartesian_product()
+            .map(|floats| floats.iter().fold(addr, |acc, f| acc ^ f))
+            .collect()
+    }
+}
+
+impl MemSet {
+    pub fn parse(line: Spanned<'_>) -> Result<Self, crate::parse::ParseError> {
+        let hint = "expected `mask = <mask>` or `mem[<address>] = <value>`";
+        let (addr, val) = line
+            .strip_prefix("mem[")
+            .map_err(|e| e.with_hint(hint))?
+            .split_at_str("] = ")
+            .ok_or_else(|| {
+                line.error("expected `] = ` after the address")
+                    .with_hint(hint)
+            })?;
+        Ok(MemSet {
+            addr: addr.parse().map_err(|e| e.with_hint(hint))?,
+            val: val.parse().map_err(|e| e.with_hint(hint))?,
+        })
+    }
+}
+
+impl MaskBlock {
+    /// A block with the mask of a `mask = ` line and no writes yet
+    pub fn parse(line: Spanned<'_>) -> Result<Self, crate::parse::ParseError> {
+        let hint = format!("mask must be {} chars of 0/1/X", BITS);
+        let chars = line.strip_prefix("mask = ")?;
+        let mask: Vec<MaskVal> = chars
+            .chars()
+            .map(|(c, span)| match c {
+                '0' => Ok(MaskVal::V0),
+                '1' => Ok(MaskVal::V1),
+                'X' => Ok(MaskVal::X),
+                _ => Err(span
+                    .error(format!("expected 0, 1 or X, got `{}`", c))
+                    .with_hint(&hint)),
+            })
+            .collect::<Result<_, _>>()?;
+        if mask.len() != BITS {
+            return Err(chars
+                .error(format!("mask of {} chars", mask.len()))
+                .with_hint(&hint));
+        }
+        Ok(MaskBlock {
+            mask,
+            writes: Vec::new(),
+        })
+    }
+}
+
+#[instrument(skip_all)]
+pub fn parse(input: &str) -> Result<Vec<MaskBlock>, crate::parse::ParseError> {
+    let mut mask_blocks: Vec<MaskBlock> = Vec::new();
+    for line in lines(input) {
+        if line.value.starts_with("mask") {
+            mask_blocks.push(MaskBlock::parse(line)?);
+        } else {
+            mask_blocks
+                .last_mut()
+                .ok_or_else(|| {
+                    line.error("write before any mask")
+                        .with_hint("expected the program to start with `mask = <mask>`")
+                })?
+                .writes
+                .push(MemSet::parse(line)?);
+        }
+    }
+    Ok(mask_blocks)
+}
+
+#[instrument(skip_all)]
+pub fn part1(blocks: &[MaskBlock]) -> Solution<M> {
+    let mut memory: HashMap<M, M> = HashMap::new();
+    for block in blocks {
+        debug!("block\n{}", block);
+        let masks = block.get_val_masks();
+        for write in &block.writes {
+            let val = write.get_val(&masks);
+            memory.insert(write.addr, val);
+            trace!(addr = write.addr, val, "write");
+        }
+    }
+    let sum = memory.values().sum::<M>();
+    let writes: usize = blocks.iter().map(|block| block.writes.len()).sum();
+    let steps = vec![
+        format!(
+            "Ran {} masks over the values of {} writes, 0 and 1 overriding bits and X keeping them",
+            blocks.len(),
+            writes
+        ),
+        format!(
+            "{} addresses end up written, later writes replacing earlier ones",
+            memory.len()
+        ),
+        format!("Their values sum to {}", sum),
+    ];
+    Solution::new(sum, format!("Sum: {}", sum)).with_steps(steps)
+}
+
+#[instrument(skip_all)]
+pub fn part2(blocks: &[MaskBlock]) -> Solution<M> {
+    let mut memory: HashMap<M, M> = HashMap::new();
+    let mut writes = 0;
+    for block in blocks {
+        debug!("block\n{}", block);
+        let masks = block.get_addr_masks();
+        for write in &block.writes {
+            for addr in write.get_addr(&masks) {
+                memory.insert(addr, write.val);
+                trace!(addr, val = write.val, "write");
+                writes += 1;
+            }
+        }
+    }
+    let sum = memory.values().sum::<M>();
+    let steps = vec![
+        format!(
+            "Ran {} masks over the addresses, 1 setting bits and each X taking both values",
+            blocks.len()
+        ),
+        format!(
+            "The floating bits turned the writes into {} writes to {} addresses",
+            writes,
+            memory.len()
+        ),
+        format!("Their values sum to {}", sum),
+    ];
+    Solution::new(sum, format!("Sum: {}", sum)).with_steps(steps)
+}
+
+pub const META: Meta = Meta {
+    title: "Docking Data",
+    tags: &[Tag::Vm],
+    strategies: &["value masks for part 1, floating address expansion for part 2"],
+    complexity: "O(n·2ˣ) for x floating bits",
+};
+
+pub fn solve(input: &str, _config: &Config) -> Result<Day, SolveError> {
+    let mut lap = Lap::new();
+    let blocks = parse(input)?;
+    let part1 = part1(&blocks);
+    let part1_stats = lap.lap();
+    let part2 = part2(&blocks);
+    let part2_stats = lap.lap();
+    Ok(Day {
+        stats: Parts(part1_stats, part2_stats),
+        ..Day::new(part1, part2)
+    })
+}
+
+/// The answer to a single part, without solving the other
+pub fn solve_part(input: &str, _config: &Config, part: Part) -> Result<Answer, SolveError> {
+    let blocks = parse(input)?;
+    Ok(match part {
+        Part::One => part1(&blocks).answer.into(),
+        Part::Two => part2(&blocks).answer.into(),
+    })
+}
+
+pub fn main(config: &Config) -> Result<Day, SolveError> {
+    solve(&get_string("day14.txt"), config)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::answer::Answer;
+
+    #[test]
+    fn test_main() {
+        let day = main(&Config::default()).unwrap();
+        assert_eq!(day.answers.0, Answer::Int(15919415426101));
+        assert_eq!(day.answers.1, Answer::Int(3443997590975));
+    }
+
+    #[test]
+    fn test_examples() {
+        let blocks = parse(
+            "\
+mask = XXXXXXXXXXXXXXXXXXXXXXXXXXXXX1XXXX0X
+mem[8] = 11
+mem[7] = 101
+mem[8] = 0",
+        )
+        .unwrap();
+        assert_eq!(part1(&blocks).answer, 165);
+        let blocks = parse(
+            "\
+mask = 000000000000000000000000000000X1001X
+mem[42] = 100
+mask = 00000000000000000000000000000000X0XX
+mem[26] = 1",
+        )
+        .unwrap();
+        assert_eq!(part2(&blocks).answer, 208);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let line = lines("mask = X1Y0").next().unwrap();
+        assert_eq!(MaskBlock::parse(line).unwrap_err().span.col, 10);
+        let line = lines("mem[8] 11").next().unwrap();
+        assert!(MemSet::parse(line).is_err());
+        let error = parse("mask = X10").unwrap_err();
+        assert_eq!(error.hint.unwrap(), "mask must be 36 chars of 0/1/X");
+        let error = parse("mem[8] = 11").unwrap_err();
+        assert_eq!(error.message, "write before any mask");
+    }
+}
@@ -0,0 +1,276 @@
+use super::get_string;
+use crate::answer::Answer;
+use crate::config::{self, Config};
+use crate::memory::Lap;
+use crate::meta::Meta;
+use crate::parse::{comma_list, lines, ParseError};
+use crate::repl::{arg, ReplError, Session};
+use crate::{Day, Part, Parts, Solution, SolveError};
+
+use itertools::Itertools;
+use serde::Deserialize;
+use std::collections::HashMap;
+use tracing::{debug, instrument, trace};
+
+#[derive(Debug, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Params {
+    /// Replaces the starting numbers from the input
+    pub start: Option<Vec<usize>>,
+    pub part1_turn: usize,
+    pub part2_turn: usize,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Params {
+            start: None,
+            part1_turn: 2020,
+            part2_turn: 30000000,
+        }
+    }
+}
+
+impl config::Params for Params {
+    fn validate(&self) -> Result<(), (&'static str, String)> {
+        let first = |turn| match turn {
+            0 => Err("turns start at 1".to_string()),
+            _ => Ok(()),
+        };
+        first(self.part1_turn).map_err(|e| ("part1_turn", e))?;
+        first(self.part2_turn).map_err(|e| ("part2_turn", e))?;
+        match &self.start {
+            Some(start) if start.is_empty() => {
+                Err(("start", "at least one starting number".to_string()))
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Turns between two traced ones, part 2 plays 30 million
+const TRACE_EVERY: usize = 1_000_000;
+
+#[derive(Clone)]
+struct MemoryGame {
+    mem: HashMap<usize, usize>,
+    last_spoken: usize,
+    last_turn: usize,
+}
+
+impl MemoryGame {
+    fn new(start: &[usize]) -> MemoryGame {
+        let mut mem: HashMap<usize, usize> = HashMap::new();
+        for (idx, v) in start.iter().enumerate() {
+            mem.insert(*v, idx + 1);
+        }
+        MemoryGame {
+            mem,
+            last_spoken: *start.last().unwrap(),
+            last_turn: start.len(),
+        }
+    }
+}
+
+impl Iterator for MemoryGame {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let spoken = match self.mem.get(&self.last_spoken) {
+            None => 0,
+            Some(prev_spoken) => self.last_turn.checked_sub(*prev_spoken).unwrap(),
+        };
+        self.mem.insert(self.last_spoken, self.last_turn);
+        self.last_spoken = spoken;
+        self.last_turn += 1;
+        if self.last_turn.is_multiple_of(TRACE_EVERY) {
+            trace!(turn = self.last_turn, spoken, "spoken");
+        }
+        Some(self.last_spoken)
+    }
+}
+
+#[instrument(skip_all)]
+pub fn parse(input: &str) -> Result<Vec<usize>, ParseError> {
+    let hint = "expected the starting numbers separated by commas, like `0,3,6`";
+    let line = lines(input)
+        .next()
+        .ok_or_else(|| ParseError::at_end(input, "missing the starting numbers").with_hint(hint))?;
+    comma_list(line).map_err(|e| e.with_hint(hint))
+}
+
+fn spoken_at(start: &[usize], turn: usize) -> Solution<usize> {
+    let spoken = match turn.checked_sub(start.len() + 1) {
+        Some(after) => MemoryGame::new(start).nth(after).unwrap(),
+        None => start[turn - 1],
+    };
+    let steps = vec![
+        format!(
+            "Turns 1 to {} say the start: {}",
+            start.len(),
+            start.iter().join(", ")
+        ),
+        "Each next turn says how many turns apart the last number was said before, or 0"
+            .to_string(),
+        format!(
+            "Turns {} to {}: {}",
+            start.len() + 1,
+            start.len() + 5,
+            MemoryGame::new(start).take(5).join(", ")
+        ),
+        format!("Turn {} says {}", turn, spoken),
+    ];
+    Solution::new(spoken, format!("{} = turn {}", spoken, turn)).with_steps(steps)
+}
+
+#[instrument(skip_all)]
+pub fn part1(start: &[usize], params: &Params) -> Solution<usize> {
+    spoken_at(start, params.part1_turn)
+}
+
+#[instrument(skip_all)]
+pub fn part2(start: &[usize], params: &Params) -> Solution<usize> {
+    spoken_at(start, params.part2_turn)
+}
+
+pub const META: Meta = Meta {
+    title: "Rambunctious Recitation",
+    tags: &[],
+    strategies: &["map of the last turn each number was spoken"],
+    complexity: "O(k) for turn k",
+};
+
+pub fn solve(input: &str, config: &Config) -> Result<Day, SolveError> {
+    let mut lap = Lap::new();
+    let params: Params = config.checked("day15")?;
+    let start = match &params.start {
+        Some(start) => start.clone(),
+        None => parse(input)?,
+    };
+    debug!(
+        ?start,
+        next = ?MemoryGame::new(&start).take(10).collect::<Vec<_>>(),
+        "first turns"
+    );
+    let part1 = part1(&start, &params);
+    let part1_stats = lap.lap();
+    let part2 = part2(&start, &params);
+    let part2_stats = lap.lap();
+    Ok(Day {
+        stats: Parts(part1_stats, part2_stats),
+        ..Day::new(part1, part2)
+    })
+}
+
+/// The answer to a single part, without solving the other
+pub fn solve_part(input: &str, config: &Config, part: Part) -> Result<Answer, SolveError> {
+    let params: Params = config.checked("day15")?;
+    let start = match &params.start {
+        Some(start) => start.clone(),
+        None => parse(input)?,
+    };
+    Ok(match part {
+        Part::One => part1(&start, &params).answer.into(),
+        Part::Two => part2(&start, &params).answer.into(),
+    })
+}
+
+pub fn main(config: &Config) -> Result<Day, SolveError> {
+    solve(&get_string("day15.txt"), config)
+}
+
+/// Numbers spoken at any turn
+pub struct Repl {
+    start: Vec<usize>,
+}
+
+impl Repl {
+    pub fn open(input: &str, config: &Config) -> Box<dyn Session> {
+        let params: Params = config.checked("day15").unwrap_or_else(|e| panic!("{}", e));
+        Box::new(Repl {
+            start: params
+                .start
+                .unwrap_or_else(|| parse(input).unwrap_or_else(|e| panic!("{}", e))),
+        })
+    }
+}
+
+impl Session for Repl {
+    fn commands(&self) -> &'static [(&'static str, &'static str)] {
+        &[("turn <k>", "number spoken at turn k")]
+    }
+
+    fn eval(&mut self, command: &str, args: &[&str]) -> Result<String, ReplError> {
+        if command != "turn" {
+            return Err(ReplError::UnknownCommand(command.to_string()));
+        }
+        let turn: usize = arg(args, 0)?;
+        if turn == 0 {
+            return Err(ReplError::Invalid("turns start at 1".to_string()));
+        }
+        Ok(match self.start.get(turn - 1) {
+            Some(spoken) => format!("{} = turn {}, a starting number", spoken, turn),
+            None => spoken_at(&self.start, turn).display,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_repl() {
+        let mut repl = Repl::open("0,3,6", &Config::default());
+        assert_eq!(
+            repl.eval("turn", &["2"]),
+            Ok("3 = turn 2, a starting number".to_string())
+        );
+        assert_eq!(repl.eval("turn", &["4"]), Ok("0 = turn 4".to_string()));
+        assert_eq!(
+            repl.eval("turn", &["2020"]),
+            Ok("436 = turn 2020".to_string())
+        );
+        assert!(matches!(
+            repl.eval("turn", &["0"]),
+            Err(ReplError::Invalid(_))
+        ));
+    }
+
+    #[test]
+    fn test_params() {
+        let mut config = Config::default();
+        config.set("day15.part1_turn=2").unwrap();
+        assert_eq!(
+            solve("0,3,6", &config).unwrap().answers.0,
+            crate::answer::Answer::Int(3)
+        );
+        config.set("day15.part2_turn=0").unwrap();
+        assert!(matches!(
+            config.checked::<Params>("day15"),
+            Err(config::ConfigError::Value(_, _))
+        ));
+    }
+
+    #[test]
+    fn test_examples_part1() {
+        let examples = vec![
+            (vec![0, 3, 6], 436),
+            (vec![1, 3, 2], 1),
+            (vec![2, 1, 3], 10),
+            (vec![1, 2, 3], 27),
+            (vec![2, 3, 1], 78),
+            (vec![3, 2, 1], 438),
+            (vec![3, 1, 2], 1836),
+        ];
+        for example in examples {
+            let mut mem_game = MemoryGame::new(&example.0);
+            assert_eq!(
+                mem_game.nth(2020 - example.0.len() - 1).unwrap(),
+                example.1,
+                "{:#?}",
+                example.0
+            );
+        }
+    }
+}
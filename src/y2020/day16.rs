@@ -0,0 +1,342 @@
+use itertools::Itertools;
+use recap::Recap;
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::collections::HashSet;
+use tracing::{debug, instrument, trace};
+
+use super::get_string;
+use crate::answer::Answer;
+use crate::config::{self, Config};
+use crate::memory::Lap;
+use crate::meta::{Meta, Tag};
+use crate::parse::{comma_list, paragraphs, ParseError, Spanned};
+use crate::{Day, Part, Parts, Solution, SolveError};
+
+#[derive(Debug, Deserialize, Recap)]
+#[recap(regex = r#"^(?P<name>.+): (?P<r1>\d+)-(?P<r2>\d+) or (?P<r3>\d+)-(?P<r4>\d+)$"#)]
+pub struct RuleStr {
+    name: String,
+    r1: String,
+    r2: String,
+    r3: String,
+    r4: String,
+}
+
+#[derive(Clone, Debug)]
+pub struct Rule {
+    ranges: Vec<(usize, usize)>,
+}
+
+impl Rule {
+    pub fn new(a: (usize, usize), b: (usize, usize)) -> Rule {
+        Rule { ranges: vec![a, b] }
+    }
+    pub fn check_val(&self, val: usize) -> bool {
+        self.ranges.iter().any(|r| r.0 <= val && val <= r.1)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Ticket {
+    fields: Vec<usize>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Notes {
+    rules: HashMap<String, Rule>,
+    ticket: Ticket,
+    nearby: Vec<Ticket>,
+}
+
+fn parse_rule(line: Spanned<'_>) -> Result<(String, Rule), ParseError> {
+    let rule_str = line.parse::<RuleStr>().map_err(|_| {
+        line.error(format!("invalid rule `{}`", line.value))
+            .with_hint("expected `<name>: <a>-<b> or <c>-<d>`, like `class: 1-3 or 5-7`")
+    })?;
+    let bound = |s: &str| s.parse().map_err(|e| line.error(format!("`{}`: {}", s, e)));
+    let ranges = vec![
+        (bound(&rule_str.r1)?, bound(&rule_str.r2)?),
+        (bound(&rule_str.r3)?, bound(&rule_str.r4)?),
+    ];
+    Ok((rule_str.name, Rule { ranges }))
+}
+
+/// A `your ticket:` or `nearby tickets:` section, its heading then tickets
+fn parse_tickets(
+    input: &str,
+    section: Option<&Vec<Spanned<'_>>>,
+    heading: &str,
+) -> Result<Vec<Ticket>, ParseError> {
+    let hint =
+        "expected the rules, `your ticket:` then `nearby tickets:`, separated by blank lines";
+    let section = section.ok_or_else(|| {
+        ParseError::at_end(input, format!("missing the `{}` section", heading)).with_hint(hint)
+    })?;
+    section[0].expect(heading).map_err(|e| e.with_hint(hint))?;
+    section[1..]
+        .iter()
+        .map(|&t| {
+            Ok(Ticket {
+                fields: comma_list(t)
+                    .map_err(|e| e.with_hint("expected field values separated by commas"))?,
+            })
+        })
+        .collect()
+}
+
+#[instrument(skip_all)]
+pub fn parse(s: &str) -> Result<Notes, ParseError> {
+    let groups = paragraphs(s);
+    let mut groups = groups.iter();
+    let rules = match groups.next() {
+        Some(lines) => lines
+            .iter()
+            .map(|&line| parse_rule(line))
+            .collect::<Result<_, _>>()?,
+        None => HashMap::new(),
+    };
+    let ticket = parse_tickets(s, groups.next(), "your ticket:")?
+        .pop()
+        .ok_or_else(|| ParseError::at_end(s, "missing my ticket"))?;
+    let nearby = parse_tickets(s, groups.next(), "nearby tickets:")?;
+    Ok(Notes {
+        rules,
+        ticket,
+        nearby,
+    })
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Params {
+    pub prefix: String,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Params {
+            prefix: "departure".to_string(),
+        }
+    }
+}
+
+impl config::Params for Params {}
+
+#[instrument(skip_all)]
+pub fn part1(notes: &Notes) -> Solution<usize> {
+    let invalid: Vec<usize> = notes
+        .nearby
+        .iter()
+        .flat_map(|ticket| ticket.fields.iter().copied())
+        .filter(|&val| !notes.rules.values().any(|r| r.check_val(val)))
+        .collect();
+    let ticket_scanning_error_rate: usize = invalid.iter().sum();
+    let steps = vec![
+        format!(
+            "Checked every value of {} nearby tickets against {} rules",
+            notes.nearby.len(),
+            notes.rules.len()
+        ),
+        format!(
+            "{} values fit no rule: {}",
+            invalid.len(),
+            invalid.iter().take(10).join(", ") + if invalid.len() > 10 { ", ..." } else { "" }
+        ),
+        format!("Their sum is {}", ticket_scanning_error_rate),
+    ];
+    Solution::new(
+        ticket_scanning_error_rate,
+        format!("{} ticket scanning error rate", ticket_scanning_error_rate),
+    )
+    .with_steps(steps)
+}
+
+#[instrument(skip_all)]
+pub fn part2(notes: &Notes, params: &Params) -> Solution<usize> {
+    let rules = &notes.rules;
+    let ticket = &notes.ticket;
+    let mut nearby = notes.nearby.clone();
+    let scanned = nearby.len();
+    nearby.retain(|ticket| {
+        ticket
+            .fields
+            .iter()
+            .all(|&val| rules.values().any(|r| r.check_val(val)))
+    });
+    debug!(scanned, valid = nearby.len(), "discarded invalid tickets");
+    let mut steps = vec![format!(
+        "Discarded {} of {} nearby tickets with a value no rule allows",
+        scanned - nearby.len(),
+        scanned
+    )];
+    let fields_count = nearby.first().unwrap().fields.len();
+    let valid_idx_rule: HashMap<usize, HashSet<&str>> = (0..fields_count)
+        .map(|field_idx| {
+            let fields: Vec<usize> = nearby
+                .iter()
+                .map(|ticket| ticket.fields[field_idx])
+                .collect();
+            let rule_names: HashSet<&str> = rules
+                .iter()
+                .filter_map(move |(rule_name, rule)| {
+                    if fields.iter().all(|&f| rule.check_val(f)) {
+                        Some(rule_name.as_str())
+                    } else {
+                        None
+                    }
+                })
+                .collect();
+            (field_idx, rule_names)
+        })
+        .collect();
+    trace!(?valid_idx_rule, "candidate rules per field");
+    let mut rule_idx: HashMap<&str, usize> = HashMap::new();
+    let mut round = 0;
+    while rule_idx.len() < fields_count {
+        round += 1;
+        let mapped_rules: HashSet<&str> = rule_idx.keys().copied().collect();
+        let mut fixed: Vec<(usize, &str)> = valid_idx_rule
+            .iter()
+            .filter_map(|(&field_idx, rule_names)| {
+                let mut unmapped_rule_names = rule_names.difference(&mapped_rules);
+                match (unmapped_rule_names.next(), unmapped_rule_names.next()) {
+                    (Some(&rule_name), None) => Some((field_idx, rule_name)),
+                    _ => None,
+                }
+            })
+            .collect();
+        fixed.sort_unstable();
+        for (field_idx, rule_name) in fixed {
+            debug!(%rule_name, field_idx, "only candidate left");
+            if rule_idx.insert(rule_name, field_idx).is_none() {
+                let candidates = valid_idx_rule[&field_idx].len();
+                steps.push(format!(
+                    "Round {}: field {} is `{}`, the last of its {} candidate{} still free",
+                    round,
+                    field_idx,
+                    rule_name,
+                    candidates,
+                    if candidates == 1 { "" } else { "s" }
+                ));
+            }
+        }
+    }
+    let mut departures: Vec<(usize, &str)> = rule_idx
+        .iter()
+        .filter(|(rule_name, _)| rule_name.starts_with(&params.prefix))
+        .map(|(&rule_name, &field_idx)| (field_idx, rule_name))
+        .collect();
+    departures.sort_unstable();
+    let ticket_departures: Vec<usize> = departures
+        .iter()
+        .map(|&(field_idx, rule_name)| {
+            steps.push(format!(
+                "`{}` is field {}, {} on my ticket",
+                rule_name, field_idx, ticket.fields[field_idx]
+            ));
+            ticket.fields[field_idx]
+        })
+        .collect();
+    let ticket_departures_product = ticket_departures.iter().product::<usize>();
+    Solution::new(
+        ticket_departures_product,
+        format!(
+            "{} = {}, the {} fields on my ticket",
+            ticket_departures_product,
+            ticket_departures
+                .iter()
+                .map(|v| v.to_string())
+                .collect::<Vec<String>>()
+                .join("×"),
+            params.prefix,
+        ),
+    )
+    .with_steps(steps)
+}
+
+pub const META: Meta = Meta {
+    title: "Ticket Translation",
+    tags: &[Tag::Graph],
+    strategies: &["discard invalid tickets, then assign fields with a single candidate rule"],
+    complexity: "O(f²·t)",
+};
+
+pub fn solve(input: &str, config: &Config) -> Result<Day, SolveError> {
+    let mut lap = Lap::new();
+    let params: Params = config.checked("day16")?;
+    let notes = parse(input)?;
+    let part1 = part1(&notes);
+    let part1_stats = lap.lap();
+    let part2 = part2(&notes, &params);
+    let part2_stats = lap.lap();
+    Ok(Day {
+        stats: Parts(part1_stats, part2_stats),
+        ..Day::new(part1, part2)
+    })
+}
+
+/// The answer to a single part, without solving the other
+pub fn solve_part(input: &str, config: &Config, part: Part) -> Result<Answer, SolveError> {
+    let params: Params = config.checked("day16")?;
+    let notes = parse(input)?;
+    Ok(match part {
+        Part::One => part1(&notes).answer.into(),
+        Part::Two => part2(&notes, &params).answer.into(),
+    })
+}
+
+pub fn main(config: &Config) -> Result<Day, SolveError> {
+    solve(&get_string("day16_am.txt"), config)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::answer::Answer;
+
+    #[test]
+    fn test_main() {
+        let day = solve(&get_string("day16.txt"), &Config::default()).unwrap();
+        assert_eq!(day.answers.0, Answer::Int(32842));
+        assert_eq!(day.answers.1, Answer::Int(2628667251989));
+    }
+
+    #[test]
+    fn test_parse() {
+        let notes = "class: 1-3 or 5-7\n\nyour ticket:\n7,1,14\n\nnearby tickets:\n7,3,47";
+        assert_eq!(parse(notes).unwrap().nearby.len(), 1);
+        let error = parse(&notes.replace("1-3 or", "1-3 and")).unwrap_err();
+        assert_eq!((error.span.line, error.span.col), (1, 1));
+        let error = parse(&notes.replace("7,3,47", "7,3,4y")).unwrap_err();
+        assert_eq!((error.span.line, error.span.col), (7, 5));
+        let error = parse(&notes[..notes.find("\n\nnearby").unwrap()]).unwrap_err();
+        assert_eq!(error.message, "missing the `nearby tickets:` section");
+        assert_eq!(error.span.line, 5);
+    }
+
+    #[test]
+    fn test_steps() {
+        let notes = parse(
+            "class: 0-1 or 4-19\nrow: 0-5 or 8-19\nseat: 0-13 or 16-19\n\n\
+             your ticket:\n11,12,13\n\n\
+             nearby tickets:\n3,9,18\n15,1,5\n5,14,9\n20,1,1",
+        )
+        .unwrap();
+        let params = Params {
+            prefix: "seat".to_string(),
+        };
+        let solution = part2(&notes, &params);
+        assert_eq!(solution.answer, 13);
+        assert_eq!(
+            solution.steps,
+            [
+                "Discarded 1 of 4 nearby tickets with a value no rule allows",
+                "Round 1: field 0 is `row`, the last of its 1 candidate still free",
+                "Round 2: field 1 is `class`, the last of its 2 candidates still free",
+                "Round 3: field 2 is `seat`, the last of its 3 candidates still free",
+                "`seat` is field 2, 13 on my ticket",
+            ]
+        );
+    }
+}
@@ -0,0 +1,312 @@
+use itertools::Itertools;
+use serde::Deserialize;
+use std::convert::TryFrom;
+use tracing::{debug, instrument, trace};
+
+use super::get_string;
+use crate::answer::Answer;
+use crate::automaton::{Automaton, Sparse, Topology};
+use crate::config::{self, Config};
+use crate::geom::VecN;
+use crate::memory::Lap;
+use crate::meta::{Meta, Tag};
+use crate::parse::{self, Cell};
+use crate::{Day, Part, Parts, Solution, SolveError};
+
+const MAX_DIMENSIONS: usize = 4;
+
+type Cood = VecN<i64, MAX_DIMENSIONS>;
+
+#[derive(Clone, PartialEq, Eq, Hash)]
+enum CubeState {
+    Active,
+    Inactive,
+}
+
+impl Cell for CubeState {
+    const CHARS: &'static str = ".#";
+
+    fn from_char(c: char) -> Option<Self> {
+        match c {
+            '.' => Some(Self::Inactive),
+            '#' => Some(Self::Active),
+            _ => None,
+        }
+    }
+}
+
+impl std::fmt::Display for CubeState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::Inactive => ".",
+                Self::Active => "#",
+            }
+        )
+    }
+}
+
+#[derive(Clone)]
+pub struct PocketDimension {
+    grid: Sparse<Cood, CubeState>,
+}
+
+impl PocketDimension {
+    fn from_initial_state(s: &str) -> Result<PocketDimension, parse::ParseError> {
+        let mut grid = Sparse::new(CubeState::Inactive);
+        for (y, row) in parse::grid(s)?.into_iter().enumerate() {
+            for (x, v) in row.into_iter().enumerate() {
+                let cood =
+                    Cood::from_slice(&[i64::try_from(x).unwrap(), i64::try_from(y).unwrap()]);
+                grid.set(&cood, v);
+            }
+        }
+        Ok(PocketDimension { grid })
+    }
+
+    /// Returns the active cubes after each cycle
+    fn run(
+        &mut self,
+        cycles: usize,
+        dimensions: usize,
+        rules: fn(cube_state: CubeState, active_count: usize) -> CubeState,
+        parallel: bool,
+    ) -> Vec<usize> {
+        let neighbourhood = |_: &Sparse<Cood, CubeState>, cood: &Cood| {
+            std::iter::repeat_n(-1..=1, dimensions)
+                .multi_cartesian_product()
+                .filter(|delta| delta.iter().any(|&d| d != 0))
+                .map(|delta| *cood + Cood::from_slice(&delta))
+                .collect()
+        };
+        let rule = |cube_state: &CubeState, neighbours: &[CubeState]| {
+            let active_count = neighbours
+                .iter()
+                .filter(|v| matches!(v, CubeState::Active))
+                .count();
+            rules(cube_state.clone(), active_count)
+        };
+        let mut active = vec![];
+        let mut automaton = Automaton::new(self.grid.clone(), neighbourhood, rule)
+            .parallel(parallel)
+            // Inactive cubes are not stored
+            .on_generation(|cycle, grid: &Sparse<Cood, CubeState>| {
+                debug!(cycle, active = grid.len(), "cycle");
+                active.push(grid.len());
+            });
+        automaton.run(cycles);
+        self.grid = automaton.into_current();
+        active
+    }
+
+    fn count_active(&self) -> usize {
+        self.grid
+            .cells()
+            .filter(|(_, v)| matches!(v, CubeState::Active))
+            .count()
+    }
+}
+
+fn rules(cube_state: CubeState, active_count: usize) -> CubeState {
+    match (cube_state, active_count) {
+        (CubeState::Active, 2..=3) => CubeState::Active,
+        (CubeState::Active, _) => CubeState::Inactive,
+        (CubeState::Inactive, 3) => CubeState::Active,
+        (CubeState::Inactive, _) => CubeState::Inactive,
+    }
+}
+
+impl std::fmt::Display for PocketDimension {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let bounds = |axis: usize| {
+            self.grid
+                .cells()
+                .map(|(cood, _)| cood[axis])
+                .minmax()
+                .into_option()
+        };
+        let ((x_min, x_max), (y_min, y_max), (z_min, z_max)) =
+            match (bounds(0), bounds(1), bounds(2)) {
+                (Some(x), Some(y), Some(z)) => (x, y, z),
+                _ => return writeln!(f, "No active cube"),
+            };
+        for z_idx in z_min..=z_max {
+            write!(
+                f,
+                "z={:<width$} ",
+                z_idx,
+                width = usize::try_from(x_max.saturating_sub(x_min).saturating_sub(1)).unwrap()
+            )?;
+        }
+        writeln!(f)?;
+        for y_idx in y_min..=y_max {
+            for z_idx in z_min..=z_max {
+                for x_idx in x_min..=x_max {
+                    write!(
+                        f,
+                        "{}",
+                        self.grid.get(&Cood::from_slice(&[x_idx, y_idx, z_idx]))
+                    )?;
+                }
+                write!(f, " ")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Params {
+    pub cycles: usize,
+    pub part1_dimensions: usize,
+    pub part2_dimensions: usize,
+    /// Spread each cycle over all cores
+    pub parallel: bool,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Params {
+            cycles: 6,
+            part1_dimensions: 3,
+            part2_dimensions: 4,
+            parallel: false,
+        }
+    }
+}
+
+impl config::Params for Params {
+    fn validate(&self) -> Result<(), (&'static str, String)> {
+        let supported = |dimensions| match dimensions {
+            2..=MAX_DIMENSIONS => Ok(()),
+            _ => Err(format!(
+                "{} dimensions not in 2..={}",
+                dimensions, MAX_DIMENSIONS
+            )),
+        };
+        supported(self.part1_dimensions).map_err(|e| ("part1_dimensions", e))?;
+        supported(self.part2_dimensions).map_err(|e| ("part2_dimensions", e))
+    }
+}
+
+#[instrument(skip_all)]
+pub fn parse(input: &str) -> Result<PocketDimension, parse::ParseError> {
+    PocketDimension::from_initial_state(input)
+}
+
+fn active_after_cycles(
+    init_pocket_dimension: &PocketDimension,
+    params: &Params,
+    dimensions: usize,
+) -> Solution<usize> {
+    assert!(
+        (2..=MAX_DIMENSIONS).contains(&dimensions),
+        "{} dimensions not in 2..={}",
+        dimensions,
+        MAX_DIMENSIONS
+    );
+    let mut pocket_dimension = init_pocket_dimension.clone();
+    let cycles = pocket_dimension.run(params.cycles, dimensions, rules, params.parallel);
+    trace!("after {} cycles\n{}", params.cycles, pocket_dimension);
+    let active = pocket_dimension.count_active();
+    let mut steps = vec![
+        format!(
+            "{} cubes start active, in a slice of {} dimensions",
+            init_pocket_dimension.count_active(),
+            dimensions
+        ),
+        format!(
+            "An active cube stays with 2 or 3 of its {} neighbours active, an inactive one needs 3",
+            3usize.pow(u32::try_from(dimensions).unwrap()) - 1
+        ),
+    ];
+    steps.extend(
+        cycles
+            .iter()
+            .enumerate()
+            .map(|(idx, active)| format!("Cycle {}: {} active", idx + 1, active)),
+    );
+    Solution::new(active, format!("{} active cubes", active)).with_steps(steps)
+}
+
+#[instrument(skip_all)]
+pub fn part1(pocket_dimension: &PocketDimension, params: &Params) -> Solution<usize> {
+    active_after_cycles(pocket_dimension, params, params.part1_dimensions)
+}
+
+#[instrument(skip_all)]
+pub fn part2(pocket_dimension: &PocketDimension, params: &Params) -> Solution<usize> {
+    active_after_cycles(pocket_dimension, params, params.part2_dimensions)
+}
+
+pub const META: Meta = Meta {
+    title: "Conway Cubes",
+    tags: &[Tag::Grid],
+    strategies: &["sparse automaton in up to 4 dimensions, optionally parallel"],
+    complexity: "O(c·a·3ᵈ)",
+};
+
+pub fn solve(input: &str, config: &Config) -> Result<Day, SolveError> {
+    let mut lap = Lap::new();
+    let params: Params = config.checked("day17")?;
+    let pocket_dimension = parse(input)?;
+    let part1 = part1(&pocket_dimension, &params);
+    let part1_stats = lap.lap();
+    let part2 = part2(&pocket_dimension, &params);
+    let part2_stats = lap.lap();
+    Ok(Day {
+        stats: Parts(part1_stats, part2_stats),
+        ..Day::new(part1, part2)
+    })
+}
+
+/// The answer to a single part, without solving the other
+pub fn solve_part(input: &str, config: &Config, part: Part) -> Result<Answer, SolveError> {
+    let params: Params = config.checked("day17")?;
+    let pocket_dimension = parse(input)?;
+    Ok(match part {
+        Part::One => part1(&pocket_dimension, &params).answer.into(),
+        Part::Two => part2(&pocket_dimension, &params).answer.into(),
+    })
+}
+
+pub fn main(config: &Config) -> Result<Day, SolveError> {
+    solve(&get_string("day17.txt"), config)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+.#.
+..#
+###";
+
+    #[test]
+    fn test_example() {
+        let mut pocket_dimension = PocketDimension::from_initial_state(EXAMPLE).unwrap();
+        pocket_dimension.run(6, 3, rules, false);
+        assert_eq!(pocket_dimension.count_active(), 112);
+    }
+
+    #[test]
+    fn test_display() {
+        let mut pocket_dimension = parse(EXAMPLE).unwrap();
+        insta::assert_snapshot!("initial_state", pocket_dimension.to_string());
+        pocket_dimension.run(1, 3, rules, false);
+        insta::assert_snapshot!("after_1_cycle", pocket_dimension.to_string());
+    }
+
+    #[test]
+    fn test_display_empty() {
+        // A lone cube has no neighbour to stay active
+        let mut pocket_dimension = parse("#").unwrap();
+        pocket_dimension.run(1, 3, rules, false);
+        assert_eq!(pocket_dimension.to_string(), "No active cube\n");
+    }
+}
@@ -0,0 +1,149 @@
+use tracing::{debug, instrument};
+
+use super::get_string;
+use crate::answer::Answer;
+use crate::config::Config;
+use crate::memory::Lap;
+use crate::meta::Meta;
+use crate::parse::{lines, ParseError, Spanned};
+use crate::{Day, Part, Parts, Solution, SolveError};
+
+/// A `1-3 a: abcde` line, borrowing the password
+#[derive(Debug)]
+pub struct PasswordEntry<'a> {
+    lower: usize,
+    upper: usize,
+    character: char,
+    password: &'a str,
+}
+
+impl<'a> PasswordEntry<'a> {
+    pub fn parse(line: Spanned<'a>) -> Result<Self, ParseError> {
+        let missing = |what: &str| line.error(format!("expected {}", what));
+        let (range, rest) = line.split_once(' ').ok_or_else(|| missing("a policy"))?;
+        let (lower, upper) = range.split_once('-').ok_or_else(|| missing("a range"))?;
+        let (character, password) = rest
+            .split_at_str(": ")
+            .ok_or_else(|| missing("`: ` before the password"))?;
+        Ok(PasswordEntry {
+            lower: lower.parse()?,
+            upper: upper.parse()?,
+            character: character.parse()?,
+            password: password.value,
+        })
+    }
+}
+
+#[instrument(skip_all)]
+pub fn parse(input: &str) -> Result<Vec<PasswordEntry<'_>>, ParseError> {
+    lines(input)
+        .map(|line| {
+            PasswordEntry::parse(line)
+                .map_err(|e| e.with_hint("expected `<lower>-<upper> <letter>: <password>`"))
+        })
+        .collect()
+}
+
+#[instrument(skip_all)]
+pub fn part1(database: &[PasswordEntry<'_>]) -> Solution<usize> {
+    let valid_count = database
+        .iter()
+        .filter(|entry| {
+            let count = entry
+                .password
+                .chars()
+                .filter(|c| c == &entry.character)
+                .count();
+            (entry.lower <= count) && (count <= entry.upper)
+        })
+        .count();
+    let steps = vec![
+        "Counted the policy letter in each password".to_string(),
+        format!(
+            "{} of {} entries have a count between their bounds",
+            valid_count,
+            database.len()
+        ),
+    ];
+    Solution::new(valid_count, format!("{} valid entries", valid_count)).with_steps(steps)
+}
+
+#[instrument(skip_all)]
+pub fn part2(database: &[PasswordEntry<'_>]) -> Solution<usize> {
+    let valid_count = database
+        .iter()
+        .filter(|entry| {
+            let at = |position: usize| entry.password.chars().nth(position - 1);
+            let pos1: bool = at(entry.lower) == Some(entry.character);
+            let pos2: bool = at(entry.upper) == Some(entry.character);
+            pos1 ^ pos2
+        })
+        .count();
+    let steps = vec![
+        "Read each password at the two 1-based positions of its policy".to_string(),
+        format!(
+            "{} of {} entries have the policy letter at exactly one of them",
+            valid_count,
+            database.len()
+        ),
+    ];
+    Solution::new(valid_count, format!("{} valid entries", valid_count)).with_steps(steps)
+}
+
+pub const META: Meta = Meta {
+    title: "Password Philosophy",
+    tags: &[],
+    strategies: &["count or index the policy letter in each password"],
+    complexity: "O(n·l)",
+};
+
+pub fn solve(input: &str, _config: &Config) -> Result<Day, SolveError> {
+    let mut lap = Lap::new();
+    let database = parse(input)?;
+    debug!(count = database.len(), first = ?database.first(), "parsed");
+    let part1 = part1(&database);
+    let part1_stats = lap.lap();
+    let part2 = part2(&database);
+    let part2_stats = lap.lap();
+    Ok(Day {
+        stats: Parts(part1_stats, part2_stats),
+        ..Day::new(part1, part2)
+    })
+}
+
+/// The answer to a single part, without solving the other
+pub fn solve_part(input: &str, _config: &Config, part: Part) -> Result<Answer, SolveError> {
+    let database = parse(input)?;
+    Ok(match part {
+        Part::One => part1(&database).answer.into(),
+        Part::Two => part2(&database).answer.into(),
+    })
+}
+
+pub fn main(config: &Config) -> Result<Day, SolveError> {
+    solve(&get_string("day2.txt"), config)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::answer::Answer;
+
+    #[test]
+    fn test_main() {
+        let day = main(&Config::default()).unwrap();
+        assert_eq!(day.answers.0, Answer::Int(483));
+        assert_eq!(day.answers.1, Answer::Int(482));
+    }
+
+    #[test]
+    fn test_parse() {
+        let entries = parse("1-3 a: abcde\n2-9 c: ccccccccc").unwrap();
+        assert_eq!(entries[1].upper, 9);
+        assert_eq!(entries[1].password, "ccccccccc");
+        let line = lines("1-3 a abcde").next().unwrap();
+        assert!(PasswordEntry::parse(line).is_err());
+        let line = lines("1-x a: abcde").next().unwrap();
+        assert_eq!(PasswordEntry::parse(line).unwrap_err().span.col, 3);
+    }
+}
@@ -0,0 +1,217 @@
+use std::convert::TryFrom;
+use std::fmt;
+
+use colored::*;
+use itertools::Itertools;
+use serde::Deserialize;
+use tracing::{debug, instrument, trace};
+
+use super::get_string;
+use crate::answer::Answer;
+use crate::config::{self, Config};
+use crate::geom::Vec2;
+use crate::grid::Grid;
+use crate::memory::Lap;
+use crate::meta::{Meta, Tag};
+use crate::parse::{Cell, ParseError};
+use crate::{Day, Part, Parts, Solution, SolveError};
+
+#[derive(Clone)]
+pub enum Pos {
+    Open,
+    Tree,
+}
+
+impl fmt::Display for Pos {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Pos::Open => '.',
+                Pos::Tree => '#',
+            }
+        )
+    }
+}
+
+impl Cell for Pos {
+    const CHARS: &'static str = ".#";
+
+    fn from_char(c: char) -> Option<Self> {
+        match c {
+            '.' => Some(Pos::Open),
+            '#' => Some(Pos::Tree),
+            _ => None,
+        }
+    }
+}
+
+pub struct Map {
+    grid: Grid<Pos>,
+}
+
+impl fmt::Display for Map {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.grid.rows() {
+            let pattern = row.iter().map(|pos| pos.to_string()).collect::<String>();
+            writeln!(f, "{}{}{}", pattern.blue(), pattern, pattern.blue())?
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Params {
+    pub slope: Vec2<usize>,
+    pub slopes: Vec<Vec2<usize>>,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Params {
+            slope: Vec2::new(3, 1),
+            slopes: vec![
+                Vec2::new(1, 1),
+                Vec2::new(3, 1),
+                Vec2::new(5, 1),
+                Vec2::new(7, 1),
+                Vec2::new(1, 2),
+            ],
+        }
+    }
+}
+
+impl config::Params for Params {
+    fn validate(&self) -> Result<(), (&'static str, String)> {
+        let down = "slopes must move down, `y` can't be 0".to_string();
+        if self.slope.y == 0 {
+            Err(("slope", down))
+        } else if self.slopes.iter().any(|slope| slope.y == 0) {
+            Err(("slopes", down))
+        } else {
+            Ok(())
+        }
+    }
+}
+
+#[instrument(skip_all)]
+pub fn parse(input: &str) -> Result<Map, ParseError> {
+    Ok(Map {
+        grid: input.parse()?,
+    })
+}
+
+pub fn traverse(map: &Map, trajectory: Vec2<usize>) -> usize {
+    let trees = (0..map.grid.height())
+        .step_by(trajectory.y)
+        .enumerate()
+        .filter(|&(step, y)| {
+            let x = i64::try_from(step * trajectory.x).unwrap();
+            matches!(
+                map.grid.get_wrapping(x, i64::try_from(y).unwrap()),
+                Pos::Tree
+            )
+        })
+        .count();
+    debug!(slope = %trajectory, trees, "traversed");
+    trees
+}
+
+#[instrument(skip_all)]
+pub fn part1(map: &Map, params: &Params) -> Solution<usize> {
+    let trees = traverse(map, params.slope);
+    let steps = vec![format!(
+        "Slope {} down {} rows, repeating the map sideways, hits {} trees",
+        params.slope,
+        map.grid.height(),
+        trees
+    )];
+    Solution::new(trees, format!("{} trees", trees)).with_steps(steps)
+}
+
+#[instrument(skip_all)]
+pub fn part2(map: &Map, params: &Params) -> Solution<usize> {
+    let trees: Vec<usize> = params
+        .slopes
+        .iter()
+        .map(|&trajectory| traverse(map, trajectory))
+        .collect();
+    let product = trees.iter().product::<usize>();
+    let mut steps: Vec<String> = params
+        .slopes
+        .iter()
+        .zip(&trees)
+        .map(|(slope, trees)| format!("Slope {} hits {} trees", slope, trees))
+        .collect();
+    steps.push(format!("{} = {}", trees.iter().join(" × "), product));
+    Solution::new(
+        product,
+        format!(
+            "{} = {} trees",
+            product,
+            trees.iter().map(|v| v.to_string()).join(" × "),
+        ),
+    )
+    .with_steps(steps)
+}
+
+pub const META: Meta = Meta {
+    title: "Toboggan Trajectory",
+    tags: &[Tag::Grid],
+    strategies: &["walk each slope over the horizontally repeating map"],
+    complexity: "O(h) per slope",
+};
+
+pub fn solve(input: &str, config: &Config) -> Result<Day, SolveError> {
+    let mut lap = Lap::new();
+    let params: Params = config.checked("day3")?;
+    let map = parse(input)?;
+    trace!("map\n{}", map);
+    let part1 = part1(&map, &params);
+    let part1_stats = lap.lap();
+    let part2 = part2(&map, &params);
+    let part2_stats = lap.lap();
+    Ok(Day {
+        stats: Parts(part1_stats, part2_stats),
+        ..Day::new(part1, part2)
+    })
+}
+
+/// The answer to a single part, without solving the other
+pub fn solve_part(input: &str, config: &Config, part: Part) -> Result<Answer, SolveError> {
+    let params: Params = config.checked("day3")?;
+    let map = parse(input)?;
+    Ok(match part {
+        Part::One => part1(&map, &params).answer.into(),
+        Part::Two => part2(&map, &params).answer.into(),
+    })
+}
+
+pub fn main(config: &Config) -> Result<Day, SolveError> {
+    solve(&get_string("day3.txt"), config)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::answer::Answer;
+
+    #[test]
+    fn test_main() {
+        let day = main(&Config::default()).unwrap();
+        assert_eq!(day.answers.0, Answer::Int(156));
+        assert_eq!(day.answers.1, Answer::Int(3521829480));
+    }
+
+    #[test]
+    fn test_params() {
+        let mut config = Config::default();
+        config.set("day3.slopes=[{ x = 1, y = 0 }]").unwrap();
+        assert_eq!(
+            config.checked::<Params>("day3").unwrap_err().to_string(),
+            "invalid `day3.slopes`: slopes must move down, `y` can't be 0"
+        );
+    }
+}
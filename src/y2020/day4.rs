@@ -0,0 +1,314 @@
+use std::num::ParseIntError;
+
+use tracing::{debug, instrument};
+
+use super::get_string;
+use crate::answer::Answer;
+use crate::config::Config;
+use crate::memory::Lap;
+use crate::meta::Meta;
+use crate::parse::{pairs, paragraphs, ParseError, Spanned};
+use crate::{Day, Part, Parts, Solution, SolveError};
+
+#[derive(Debug)]
+pub enum Height {
+    Cm(usize),
+    In(usize),
+}
+
+#[derive(Debug)]
+pub enum EyeColor {
+    Amber,
+    Blue,
+    Brown,
+    Grey,
+    Green,
+    Hazel,
+    Other,
+}
+
+#[derive(Debug, Default)]
+pub struct PassportEntry<'a> {
+    byr: Option<Result<usize, YearError>>,
+    iyr: Option<Result<usize, YearError>>,
+    eyr: Option<Result<usize, YearError>>,
+    hgt: Option<Result<Height, HeightError>>,
+    hcl: Option<Result<&'a str, HairError>>,
+    ecl: Option<Result<EyeColor, EyeError>>,
+    pid: Option<Result<&'a str, PassportError>>,
+    #[allow(dead_code)]
+    cid: Option<&'a str>,
+}
+
+#[derive(Debug)]
+pub enum YearError {
+    Parse(ParseIntError),
+    Range,
+}
+
+#[derive(Debug, Clone)]
+pub enum HeightError {
+    Parse(ParseIntError),
+    Unit,
+    Range,
+}
+
+#[derive(Debug, Clone)]
+pub struct HairError;
+
+#[derive(Debug, Clone)]
+pub struct EyeError;
+
+#[derive(Debug, Clone)]
+pub struct PassportError;
+
+/// Parse year
+///
+/// ```
+/// # use advent2020::y2020::day4::*;
+///
+/// let range = (10, 20);
+/// assert!(matches!(parse_year("abra", range), Err(YearError::Parse(_))));
+/// assert!(matches!(parse_year("9", range), Err(YearError::Range)));
+/// assert!(matches!(parse_year("21", range), Err(YearError::Range)));
+/// assert!(matches!(parse_year("10", range), Ok(10)));
+/// assert!(matches!(parse_year("20", range), Ok(20)));
+/// ```
+pub fn parse_year<S: AsRef<str>>(s: S, (lower, upper): (usize, usize)) -> Result<usize, YearError> {
+    match s.as_ref().parse() {
+        Err(e) => Err(YearError::Parse(e)),
+        Ok(v) => {
+            if v >= lower && v <= upper {
+                Ok(v)
+            } else {
+                Err(YearError::Range)
+            }
+        }
+    }
+}
+
+/// Parse Height
+///
+/// ```
+/// # use advent2020::y2020::day4::*;
+///
+/// assert!(matches!(parse_height("abra"), Err(HeightError::Parse(_))));
+/// assert!(matches!(parse_height("100"), Err(HeightError::Unit)));
+/// assert!(matches!(parse_height("149cm"), Err(HeightError::Range)));
+/// assert!(matches!(parse_height("194cm"), Err(HeightError::Range)));
+/// assert!(matches!(parse_height("58in"), Err(HeightError::Range)));
+/// assert!(matches!(parse_height("77in"), Err(HeightError::Range)));
+/// assert!(matches!(parse_height("150cm"), Ok(Height::Cm(150))));
+/// assert!(matches!(parse_height("76in"), Ok(Height::In(76))));
+/// ```
+pub fn parse_height<S: AsRef<str>>(s: S) -> Result<Height, HeightError> {
+    match s
+        .as_ref()
+        .trim_end_matches("cm")
+        .trim_end_matches("in")
+        .parse()
+    {
+        Err(e) => Err(HeightError::Parse(e)),
+        Ok(height) => match s.as_ref().get(s.as_ref().len() - 2..) {
+            Some("cm") => match height {
+                150..=193 => Ok(Height::Cm(height)),
+                _ => Err(HeightError::Range),
+            },
+            Some("in") => match height {
+                59..=76 => Ok(Height::In(height)),
+                _ => Err(HeightError::Range),
+            },
+            _ => Err(HeightError::Unit),
+        },
+    }
+}
+
+fn parse_hair(s: &str) -> Result<&str, HairError> {
+    match s.strip_prefix('#') {
+        Some(s) if s.len() == 6 && s.chars().all(|c| c.is_ascii_hexdigit()) => Ok(s),
+        _ => Err(HairError),
+    }
+}
+
+fn parse_eye(s: &str) -> Result<EyeColor, EyeError> {
+    match s {
+        "amb" => Ok(EyeColor::Amber),
+        "blu" => Ok(EyeColor::Blue),
+        "brn" => Ok(EyeColor::Brown),
+        "gry" => Ok(EyeColor::Grey),
+        "grn" => Ok(EyeColor::Green),
+        "hzl" => Ok(EyeColor::Hazel),
+        "oth" => Ok(EyeColor::Other),
+        _ => Err(EyeError),
+    }
+}
+
+fn parse_pid(s: &str) -> Result<&str, PassportError> {
+    if s.len() == 9 && s.chars().all(|c| c.is_ascii_digit()) {
+        Ok(s)
+    } else {
+        Err(PassportError)
+    }
+}
+
+const REQUIRED: [&str; 7] = ["byr", "iyr", "eyr", "hgt", "hcl", "ecl", "pid"];
+
+impl<'a> PassportEntry<'a> {
+    /// Fills the fields straight from the `key:value` pairs, unknown keys are ignored
+    pub fn parse(lines: &[Spanned<'a>]) -> Result<Self, ParseError> {
+        let mut entry = PassportEntry::default();
+        for pair in pairs(lines) {
+            let (key, value) = pair?;
+            let s = value.value;
+            let duplicate = match key.value {
+                "byr" => entry.byr.replace(parse_year(s, (1920, 2002))).is_some(),
+                "iyr" => entry.iyr.replace(parse_year(s, (2010, 2020))).is_some(),
+                "eyr" => entry.eyr.replace(parse_year(s, (2020, 2030))).is_some(),
+                "hgt" => entry.hgt.replace(parse_height(s)).is_some(),
+                "hcl" => entry.hcl.replace(parse_hair(s)).is_some(),
+                "ecl" => entry.ecl.replace(parse_eye(s)).is_some(),
+                "pid" => entry.pid.replace(parse_pid(s)).is_some(),
+                "cid" => entry.cid.replace(s).is_some(),
+                _ => false,
+            };
+            if duplicate {
+                return Err(key.error(format!("duplicate key `{}`", key.value)));
+            }
+        }
+        Ok(entry)
+    }
+
+    /// The [`REQUIRED`] fields, `None` when missing and whether the value is valid otherwise
+    fn required(&self) -> [Option<bool>; 7] {
+        [
+            self.byr.as_ref().map(Result::is_ok),
+            self.iyr.as_ref().map(Result::is_ok),
+            self.eyr.as_ref().map(Result::is_ok),
+            self.hgt.as_ref().map(Result::is_ok),
+            self.hcl.as_ref().map(Result::is_ok),
+            self.ecl.as_ref().map(Result::is_ok),
+            self.pid.as_ref().map(Result::is_ok),
+        ]
+    }
+}
+
+#[instrument(skip_all)]
+pub fn parse(input: &str) -> Result<Vec<PassportEntry<'_>>, ParseError> {
+    paragraphs(input)
+        .iter()
+        .map(|lines| {
+            PassportEntry::parse(lines).map_err(|e| {
+                e.with_hint("expected `key:value` fields, passports separated by a blank line")
+            })
+        })
+        .collect()
+}
+
+#[instrument(skip_all)]
+pub fn part1(passports: &[PassportEntry<'_>]) -> Solution<usize> {
+    let valid_passports: usize = passports
+        .iter()
+        .filter(|passport| passport.required().iter().all(Option::is_some))
+        .count();
+    let steps = vec![format!(
+        "{} of {} passports have all 7 required fields, `cid` is optional",
+        valid_passports,
+        passports.len()
+    )];
+    Solution::new(
+        valid_passports,
+        format!("{} valid passports", valid_passports),
+    )
+    .with_steps(steps)
+}
+
+#[instrument(skip_all)]
+pub fn part2(passports: &[PassportEntry<'_>]) -> Solution<usize> {
+    let complete: Vec<[Option<bool>; 7]> = passports
+        .iter()
+        .map(PassportEntry::required)
+        .filter(|fields| fields.iter().all(Option::is_some))
+        .collect();
+    let valid_passports: usize = complete
+        .iter()
+        .filter(|fields| fields.iter().all(|&v| v == Some(true)))
+        .count();
+    let mut steps = vec![format!(
+        "Checked the values of the {} passports with every required field",
+        complete.len()
+    )];
+    for (idx, name) in REQUIRED.iter().enumerate() {
+        let invalid = complete
+            .iter()
+            .filter(|fields| fields[idx] == Some(false))
+            .count();
+        steps.push(format!("`{}` is invalid in {} of them", name, invalid));
+    }
+    steps.push(format!("{} have only valid values", valid_passports));
+    Solution::new(
+        valid_passports,
+        format!("{} valid passports", valid_passports),
+    )
+    .with_steps(steps)
+}
+
+pub const META: Meta = Meta {
+    title: "Passport Processing",
+    tags: &[],
+    strategies: &["typed field validation with an error per field"],
+    complexity: "O(n)",
+};
+
+pub fn solve(input: &str, _config: &Config) -> Result<Day, SolveError> {
+    let mut lap = Lap::new();
+    let passports = parse(input)?;
+    debug!(count = passports.len(), first = ?passports.first(), "parsed");
+    let part1 = part1(&passports);
+    let part1_stats = lap.lap();
+    let part2 = part2(&passports);
+    let part2_stats = lap.lap();
+    Ok(Day {
+        stats: Parts(part1_stats, part2_stats),
+        ..Day::new(part1, part2)
+    })
+}
+
+/// The answer to a single part, without solving the other
+pub fn solve_part(input: &str, _config: &Config, part: Part) -> Result<Answer, SolveError> {
+    let passports = parse(input)?;
+    Ok(match part {
+        Part::One => part1(&passports).answer.into(),
+        Part::Two => part2(&passports).answer.into(),
+    })
+}
+
+pub fn main(config: &Config) -> Result<Day, SolveError> {
+    solve(&get_string("day4.txt"), config)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::answer::Answer;
+
+    #[test]
+    fn test_main() {
+        let day = main(&Config::default()).unwrap();
+        assert_eq!(day.answers.0, Answer::Int(182));
+        assert_eq!(day.answers.1, Answer::Int(109));
+    }
+
+    #[test]
+    fn test_parse() {
+        let passports =
+            parse("hcl:#623a2f pid:087499704\ncid:88\n\nhcl:623a2f pid:0874997041").unwrap();
+        assert!(matches!(passports[0].hcl, Some(Ok("623a2f"))));
+        assert!(matches!(passports[0].pid, Some(Ok("087499704"))));
+        assert_eq!(passports[0].cid, Some("88"));
+        assert!(matches!(passports[1].hcl, Some(Err(HairError))));
+        assert!(matches!(passports[1].pid, Some(Err(PassportError))));
+
+        let error = PassportEntry::parse(&paragraphs("byr:1937\nbyr:1938")[0]).unwrap_err();
+        assert_eq!((error.span.line, error.span.col), (2, 1));
+    }
+}
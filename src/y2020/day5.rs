@@ -0,0 +1,197 @@
+use tracing::{debug, instrument, trace};
+
+use super::get_string;
+use crate::answer::Answer;
+use crate::config::Config;
+use crate::memory::Lap;
+use crate::meta::Meta;
+use crate::parse::{lines, ParseError, Spanned};
+use crate::{Day, Part, Parts, Solution, SolveError};
+
+/// A boarding pass, 7 of `F` or `B` for the row then 3 of `L` or `R` for the seat
+pub fn parse_pass(line: Spanned<'_>) -> Result<&str, ParseError> {
+    let hint = "expected 7 of F/B then 3 of L/R, like `FBFBBFFRLR`";
+    for (idx, (c, span)) in line.chars().enumerate() {
+        let valid = match idx {
+            0..=6 => c == 'F' || c == 'B',
+            7..=9 => c == 'L' || c == 'R',
+            _ => return Err(span.error("boarding pass too long").with_hint(hint)),
+        };
+        if !valid {
+            return Err(span.error(format!("unexpected `{}`", c)).with_hint(hint));
+        }
+    }
+    if line.value.len() < 10 {
+        return Err(line.error("boarding pass too short").with_hint(hint));
+    }
+    Ok(line.value)
+}
+
+#[instrument(skip_all)]
+pub fn parse(input: &str) -> Result<Vec<&str>, ParseError> {
+    lines(input).map(parse_pass).collect()
+}
+
+#[derive(Debug, Clone)]
+pub enum SplitDir {
+    F,
+    B,
+    L,
+    R,
+}
+
+const ROWS: usize = 128;
+const SEATS_IN_ROW: usize = 8;
+
+fn split_axis((x, y): (usize, usize), dir: SplitDir) -> (usize, usize) {
+    let y = y + 1;
+    let mid = x / 2 + y / 2;
+    let (x, y) = match dir {
+        SplitDir::F | SplitDir::L => (x, mid),
+        SplitDir::B | SplitDir::R => (mid, y),
+    };
+    (x, y - 1)
+}
+
+fn split_axis_rec((x, y): (usize, usize), dirs: &mut Vec<SplitDir>) -> usize {
+    trace!(x, y, ?dirs, "split axis");
+    let dir = dirs.pop().unwrap();
+    let (x, y) = split_axis((x, y), dir);
+    if x == y {
+        return x;
+    }
+    split_axis_rec((x, y), dirs)
+}
+
+pub fn calc(pass: &str) -> (usize, usize) {
+    let mut row_dirs = pass
+        .chars()
+        .filter_map(|c| match c {
+            'F' => Some(SplitDir::F),
+            'B' => Some(SplitDir::B),
+            _ => None,
+        })
+        .rev()
+        .collect();
+    let row = split_axis_rec((0, ROWS - 1), &mut row_dirs);
+    let mut seat_dirs = pass
+        .chars()
+        .filter_map(|c| match c {
+            'L' => Some(SplitDir::L),
+            'R' => Some(SplitDir::R),
+            _ => None,
+        })
+        .rev()
+        .collect();
+    let seat = split_axis_rec((0, SEATS_IN_ROW - 1), &mut seat_dirs);
+    (row, seat)
+}
+
+#[instrument(skip_all)]
+pub fn part1(passes: &[&str]) -> Solution<usize> {
+    let (pass, (row, seat)) = passes
+        .iter()
+        .map(|pass| (pass, calc(pass)))
+        .max_by_key(|(_, (row, seat))| row * SEATS_IN_ROW + seat)
+        .unwrap();
+    let max_seatid = row * SEATS_IN_ROW + seat;
+    let steps = vec![
+        format!(
+            "Decoded {} passes, F/L halve toward the front and B/R toward the back",
+            passes.len()
+        ),
+        format!("{} is the highest, row {} seat {}", pass, row, seat),
+        format!("{} × {} + {} = {}", row, SEATS_IN_ROW, seat, max_seatid),
+    ];
+    Solution::new(max_seatid, format!("{} is the maximum Seat ID", max_seatid)).with_steps(steps)
+}
+
+#[instrument(skip_all)]
+pub fn part2(passes: &[&str]) -> Solution<usize> {
+    let mut seatids: Vec<usize> = passes
+        .iter()
+        .map(|pass| calc(pass))
+        .map(|(row, seat)| row * SEATS_IN_ROW + seat)
+        .collect();
+    seatids.sort_unstable();
+    trace!(?seatids, "sorted");
+    let my_seatid = seatids
+        .windows(2)
+        .filter_map(|win| match win[1] - win[0] {
+            2 => Some(win[0] + 1),
+            _ => None,
+        })
+        .next()
+        .unwrap();
+    let steps = vec![
+        format!(
+            "Sorted the {} Seat IDs, from {} to {}",
+            seatids.len(),
+            seatids.first().unwrap(),
+            seatids.last().unwrap()
+        ),
+        format!(
+            "{} and {} are taken but {} is not, so it is mine",
+            my_seatid - 1,
+            my_seatid + 1,
+            my_seatid
+        ),
+    ];
+    Solution::new(my_seatid, format!("{} is my Seat ID", my_seatid)).with_steps(steps)
+}
+
+pub const META: Meta = Meta {
+    title: "Binary Boarding",
+    tags: &[],
+    strategies: &["recursive binary partition of rows and seats, then a sorted gap search"],
+    complexity: "O(n log n)",
+};
+
+pub fn solve(input: &str, _config: &Config) -> Result<Day, SolveError> {
+    let mut lap = Lap::new();
+    let passes = parse(input)?;
+    debug!(count = passes.len(), first = ?passes.first(), "parsed");
+    let part1 = part1(&passes);
+    let part1_stats = lap.lap();
+    let part2 = part2(&passes);
+    let part2_stats = lap.lap();
+    Ok(Day {
+        stats: Parts(part1_stats, part2_stats),
+        ..Day::new(part1, part2)
+    })
+}
+
+/// The answer to a single part, without solving the other
+pub fn solve_part(input: &str, _config: &Config, part: Part) -> Result<Answer, SolveError> {
+    let passes = parse(input)?;
+    Ok(match part {
+        Part::One => part1(&passes).answer.into(),
+        Part::Two => part2(&passes).answer.into(),
+    })
+}
+
+pub fn main(config: &Config) -> Result<Day, SolveError> {
+    solve(&get_string("day5.txt"), config)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::answer::Answer;
+
+    #[test]
+    fn test_main() {
+        let day = main(&Config::default()).unwrap();
+        assert_eq!(day.answers.0, Answer::Int(935));
+        assert_eq!(day.answers.1, Answer::Int(743));
+    }
+
+    #[test]
+    fn test_parse_pass() {
+        assert_eq!(parse("FBFBBFFRLR").unwrap(), ["FBFBBFFRLR"]);
+        let error = parse("FBFBBFFRLR\nFBFBBFRRLR").unwrap_err();
+        assert_eq!((error.span.line, error.span.col), (2, 7));
+        assert!(parse("FBFBBFFRL").is_err());
+        assert!(parse("FBFBBFFRLRL").is_err());
+    }
+}
@@ -0,0 +1,201 @@
+use itertools::Itertools;
+use tracing::{debug, instrument};
+
+use super::get_string;
+use crate::answer::Answer;
+use crate::config::Config;
+use crate::memory::Lap;
+use crate::meta::Meta;
+use crate::parse::{paragraphs, ParseError, Spanned};
+use crate::{Day, Part, Parts, Solution, SolveError};
+
+/// Questions answered yes, bit 0 for `a` up to bit 25 for `z`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Answers(u32);
+
+impl Answers {
+    const ALL: Answers = Answers((1 << 26) - 1);
+
+    pub fn len(self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    pub fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    pub fn union(self, other: Answers) -> Answers {
+        Answers(self.0 | other.0)
+    }
+
+    pub fn intersection(self, other: Answers) -> Answers {
+        Answers(self.0 & other.0)
+    }
+
+    pub fn letters(self) -> impl Iterator<Item = char> {
+        (b'a'..=b'z')
+            .filter(move |c| self.0 & 1 << (c - b'a') != 0)
+            .map(char::from)
+    }
+}
+
+impl Answers {
+    /// A person's line, lowercase letters only
+    pub fn parse(person: Spanned<'_>) -> Result<Self, ParseError> {
+        match person.chars().find(|(c, _)| !c.is_ascii_lowercase()) {
+            Some((c, span)) => Err(span.error(format!("unexpected `{}`", c)).with_hint(
+                "expected the questions answered yes as letters a-z, one person per line",
+            )),
+            None => Ok(Answers::from(person.value)),
+        }
+    }
+}
+
+impl From<&str> for Answers {
+    /// Anything but lowercase letters is ignored
+    fn from(s: &str) -> Self {
+        Answers(
+            s.bytes()
+                .filter(u8::is_ascii_lowercase)
+                .fold(0, |acc, c| acc | 1 << (c - b'a')),
+        )
+    }
+}
+
+#[instrument(skip_all)]
+pub fn parse(input: &str) -> Result<Vec<Vec<Answers>>, ParseError> {
+    paragraphs(input)
+        .iter()
+        .map(|group| group.iter().map(|&person| Answers::parse(person)).collect())
+        .collect()
+}
+
+fn display(answer: usize, groups: &[Answers]) -> String {
+    format!(
+        "{} = {} ...",
+        answer,
+        groups
+            .iter()
+            .map(|g| String::from("'") + &g.letters().join("") + "'")
+            .take(10)
+            .join(" + "),
+    )
+}
+
+/// How `sets`, combined from each group by `how`, add up to `answer`
+fn steps(how: &str, groups: &[Vec<Answers>], sets: &[Answers], answer: usize) -> Vec<String> {
+    let mut steps = vec![format!(
+        "Took the {} of the answers in each of {} groups",
+        how,
+        groups.len()
+    )];
+    for (idx, (group, set)) in groups.iter().zip(sets).enumerate().take(3) {
+        steps.push(format!(
+            "Group {}: {} people give '{}'",
+            idx + 1,
+            group.len(),
+            set.letters().join("")
+        ));
+    }
+    steps.push(format!(
+        "The sizes of all {} sets sum to {}",
+        sets.len(),
+        answer
+    ));
+    steps
+}
+
+#[instrument(skip_all)]
+pub fn part1(groups: &[Vec<Answers>]) -> Solution<usize> {
+    let union_groups: Vec<Answers> = groups
+        .iter()
+        .map(|group| {
+            group
+                .iter()
+                .fold(Answers::default(), |acc, &person| acc.union(person))
+        })
+        .collect();
+    let answer = union_groups.iter().map(|g| g.len()).sum::<usize>();
+    Solution::new(answer, display(answer, &union_groups)).with_steps(steps(
+        "union",
+        groups,
+        &union_groups,
+        answer,
+    ))
+}
+
+#[instrument(skip_all)]
+pub fn part2(groups: &[Vec<Answers>]) -> Solution<usize> {
+    let intersection_groups: Vec<Answers> = groups
+        .iter()
+        .map(|group| {
+            group
+                .iter()
+                .fold(Answers::ALL, |acc, &person| acc.intersection(person))
+        })
+        .collect();
+    let answer = intersection_groups.iter().map(|g| g.len()).sum::<usize>();
+    Solution::new(answer, display(answer, &intersection_groups)).with_steps(steps(
+        "intersection",
+        groups,
+        &intersection_groups,
+        answer,
+    ))
+}
+
+pub const META: Meta = Meta {
+    title: "Custom Customs",
+    tags: &[],
+    strategies: &["union and intersection of answer sets per group"],
+    complexity: "O(n)",
+};
+
+pub fn solve(input: &str, _config: &Config) -> Result<Day, SolveError> {
+    let mut lap = Lap::new();
+    let groups = parse(input)?;
+    debug!(count = groups.len(), first = ?groups.first(), "parsed");
+    let part1 = part1(&groups);
+    let part1_stats = lap.lap();
+    let part2 = part2(&groups);
+    let part2_stats = lap.lap();
+    Ok(Day {
+        stats: Parts(part1_stats, part2_stats),
+        ..Day::new(part1, part2)
+    })
+}
+
+/// The answer to a single part, without solving the other
+pub fn solve_part(input: &str, _config: &Config, part: Part) -> Result<Answer, SolveError> {
+    let groups = parse(input)?;
+    Ok(match part {
+        Part::One => part1(&groups).answer.into(),
+        Part::Two => part2(&groups).answer.into(),
+    })
+}
+
+pub fn main(config: &Config) -> Result<Day, SolveError> {
+    solve(&get_string("day6.txt"), config)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::answer::Answer;
+
+    #[test]
+    fn test_main() {
+        let day = main(&Config::default()).unwrap();
+        assert_eq!(day.answers.0, Answer::Int(6809));
+        assert_eq!(day.answers.1, Answer::Int(3394));
+    }
+
+    #[test]
+    fn test_answers() {
+        let (abc, bcd) = (Answers::from("cab"), Answers::from("bcd"));
+        assert_eq!(abc.union(bcd).letters().collect::<String>(), "abcd");
+        assert_eq!(abc.intersection(bcd).len(), 2);
+        assert!(Answers::ALL.intersection(Answers::from("")).is_empty());
+        let error = parse("abc\n\nab-c").unwrap_err();
+        assert_eq!((error.span.line, error.span.col), (3, 3));
+    }
+}
@@ -0,0 +1,514 @@
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::fmt;
+use tracing::{debug, instrument, trace};
+
+use super::get_string;
+use crate::answer::Answer;
+use crate::config::{self, Config};
+use crate::graph::{Cycle, Direction, Graph, NodeId};
+use crate::memory::Lap;
+use crate::meta::{Meta, Tag};
+use crate::parse::{lines, ParseError, Spanned};
+use crate::repl::{ReplError, Session};
+use crate::{Day, Part, Parts, Solution, SolveError};
+
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
+pub struct Bag<'a> {
+    pub attribute: &'a str,
+    pub color: &'a str,
+}
+
+impl fmt::Display for Bag<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.attribute, self.color,)
+    }
+}
+
+#[derive(Debug)]
+pub struct BagRule<'a> {
+    pub outer: Bag<'a>,
+    pub contents: Vec<(usize, Bag<'a>)>,
+}
+
+#[derive(Debug)]
+pub struct BagNode<'a> {
+    pub val: Bag<'a>,
+    pub outer: Vec<Bag<'a>>,
+    pub contents: Vec<(usize, BagNode<'a>)>,
+}
+
+impl fmt::Display for BagNode<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}\n{}",
+            self.val,
+            self.contents
+                .iter()
+                .map(|(count, node)| {
+                    format!(
+                        "{:>3} {}{}",
+                        count,
+                        node.val,
+                        node.to_string()
+                            .lines()
+                            .skip(1)
+                            .flat_map(|line| vec!["\n".to_string(), format!("    {}", line)])
+                            .collect::<Vec<String>>()
+                            .join("")
+                    )
+                })
+                .collect::<Vec<String>>()
+                .join("\n"),
+        )
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct BagRuleError;
+
+impl<'a> Bag<'a> {
+    /// `light red`, optionally followed by `bag` or `bags`
+    pub fn parse(s: &'a str) -> Result<Self, BagRuleError> {
+        let s = s
+            .strip_suffix(" bags")
+            .or_else(|| s.strip_suffix(" bag"))
+            .unwrap_or(s);
+        let (attribute, color) = s.rsplit_once(' ').ok_or(BagRuleError)?;
+        Ok(Bag { attribute, color })
+    }
+}
+
+impl<'a> BagRule<'a> {
+    pub fn parse(line: Spanned<'a>) -> Result<Self, ParseError> {
+        let bag = |s: Spanned<'a>| {
+            Bag::parse(s.value).map_err(|_| s.error(format!("expected a bag, got `{}`", s.value)))
+        };
+        let rule = match line.value.strip_suffix('.') {
+            Some(rule) => line.slice(rule),
+            None => return Err(line.error("expected a rule ending with `.`")),
+        };
+        let (outer, contents) = rule
+            .split_at_str(" contain ")
+            .ok_or_else(|| line.error("expected `contain`"))?;
+        let contents = match contents.value {
+            "no other bags" => Vec::new(),
+            _ => contents
+                .value
+                .split(", ")
+                .map(|content| {
+                    let content = contents.slice(content);
+                    let (count, inner) = content
+                        .split_once(' ')
+                        .ok_or_else(|| content.error("expected a count and a bag"))?;
+                    Ok((count.parse()?, bag(inner)?))
+                })
+                .collect::<Result<_, ParseError>>()?,
+        };
+        Ok(BagRule {
+            outer: bag(outer)?,
+            contents,
+        })
+    }
+}
+
+/// Rules of bags that never end up inside themselves
+#[instrument(skip_all)]
+pub fn parse(input: &str) -> Result<Vec<BagRule<'_>>, ParseError> {
+    let lines: Vec<Spanned<'_>> = lines(input).collect();
+    let bag_rules = lines
+        .iter()
+        .map(|&line| {
+            BagRule::parse(line).map_err(|e| {
+                e.with_hint(
+                    "expected `<bag> bags contain <n> <bag> bags, ...` or `... contain no other bags.`",
+                )
+            })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    let graph = build_graph(&bag_rules);
+    if let Some(Cycle(cycle)) = graph.find_cycle() {
+        let first = graph.node(cycle[0]);
+        let bags: Vec<String> = cycle
+            .iter()
+            .chain(&cycle[..1])
+            .map(|&id| graph.node(id).to_string())
+            .collect();
+        // Every bag of a cycle holds another, so has a rule of its own
+        let (line, _) = lines
+            .iter()
+            .zip(&bag_rules)
+            .find(|(_, rule)| rule.outer == *first)
+            .unwrap();
+        return Err(line
+            .error(format!("bags contain themselves: {}", bags.join(" → ")))
+            .with_hint("no bag can end up inside itself"));
+    }
+    Ok(bag_rules)
+}
+
+/// Edges go from the outer bag to each bag it contains, weighted by the count
+pub fn build_graph<'a>(bag_rules: &[BagRule<'a>]) -> Graph<Bag<'a>, usize> {
+    let mut graph = Graph::new();
+    for bag_rule in bag_rules {
+        let outer = graph.intern(bag_rule.outer);
+        for &(count, bag) in &bag_rule.contents {
+            let inner = graph.intern(bag);
+            graph.add_edge_ids(outer, inner, count);
+        }
+    }
+    graph
+}
+
+pub fn build_tree<'a>(graph: &Graph<Bag<'a>, usize>, start: NodeId) -> BagNode<'a> {
+    BagNode {
+        val: *graph.node(start),
+        outer: graph
+            .edges(start, Direction::Incoming)
+            .iter()
+            .map(|&(id, _)| *graph.node(id))
+            .collect(),
+        contents: graph
+            .edges(start, Direction::Outgoing)
+            .iter()
+            .map(|&(id, count)| (count, build_tree(graph, id)))
+            .collect(),
+    }
+}
+
+/// Id of `bag`, an error at the end of `input` when no rule mentions it
+fn bag_id(graph: &Graph<Bag<'_>, usize>, bag: Bag<'_>, input: &str) -> Result<NodeId, ParseError> {
+    graph.id(&bag).ok_or_else(|| {
+        ParseError::at_end(input, format!("no rule mentions {} bags", bag))
+            .with_hint("set `day7.bag` to a bag of the rules")
+    })
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Params {
+    pub bag: String,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Params {
+            bag: "shiny gold".to_string(),
+        }
+    }
+}
+
+impl config::Params for Params {
+    fn validate(&self) -> Result<(), (&'static str, String)> {
+        match Bag::parse(&self.bag) {
+            Ok(_) => Ok(()),
+            Err(_) => Err((
+                "bag",
+                format!("expected `<attribute> <color>`, got `{}`", self.bag),
+            )),
+        }
+    }
+}
+
+impl Params {
+    /// The bag to count for, checked by `validate`
+    pub fn bag(&self) -> Bag<'_> {
+        Bag::parse(&self.bag).unwrap()
+    }
+}
+
+#[instrument(skip_all)]
+pub fn part1(graph: &Graph<Bag<'_>, usize>, params: &Params) -> Solution<usize> {
+    let my_bag = params.bag();
+    let my_id = graph.id(&my_bag).unwrap();
+    let parents = graph.reachable(my_id, Direction::Incoming);
+    let bags = parents.len() - 1; // Don't count initial bag
+    let direct = graph.edges(my_id, Direction::Incoming);
+    let steps = vec![
+        format!("Built a graph of {} bag colors", graph.len()),
+        format!(
+            "{} colors hold {} directly: {}",
+            direct.len(),
+            my_bag,
+            direct
+                .iter()
+                .map(|&(id, _)| graph.node(id).to_string())
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+        format!(
+            "Following the rules outwards, {} colors eventually hold it",
+            bags
+        ),
+    ];
+    Solution::new(
+        bags,
+        format!(
+            "{} bag colors can eventually contain at least one {} bag.",
+            bags, my_bag
+        ),
+    )
+    .with_steps(steps)
+}
+
+#[instrument(skip_all)]
+pub fn part2(graph: &Graph<Bag<'_>, usize>, params: &Params) -> Solution<usize> {
+    let my_bag = params.bag();
+    let my_id = graph.id(&my_bag).unwrap();
+    let mut totals = HashMap::new();
+    // Bags inside each bag, every content counts itself and what it contains
+    let bags = graph
+        .aggregate(my_id, |bag, contents: &[(usize, &usize)]| {
+            let inside = contents
+                .iter()
+                .map(|(inside, &count)| count * (1 + inside))
+                .sum::<usize>();
+            trace!(%bag, inside, "bags inside");
+            totals.insert(*bag, inside);
+            inside
+        })
+        .expect("no cycles, checked by `parse`");
+    let mut steps = vec![format!(
+        "Counted the bags inside each of the {} colors below {}, innermost first",
+        totals.len() - 1,
+        my_bag
+    )];
+    for &(id, count) in graph.edges(my_id, Direction::Outgoing) {
+        let bag = graph.node(id);
+        steps.push(format!(
+            "{} {} bags, each holding {} more: {} × (1 + {}) = {}",
+            count,
+            bag,
+            totals[bag],
+            count,
+            totals[bag],
+            count * (1 + totals[bag])
+        ));
+    }
+    steps.push(format!("Together {} bags", bags));
+    Solution::new(
+        bags,
+        format!(
+            "{} individual bags are required inside my single {} bag.",
+            bags, my_bag
+        ),
+    )
+    .with_steps(steps)
+}
+
+pub const META: Meta = Meta {
+    title: "Handy Haversacks",
+    tags: &[Tag::Graph],
+    strategies: &["reachability over incoming edges, then aggregation over outgoing edges"],
+    complexity: "O(b + e)",
+};
+
+pub fn solve(input: &str, config: &Config) -> Result<Day, SolveError> {
+    let mut lap = Lap::new();
+    let params: Params = config.checked("day7")?;
+    let bag_rules = parse(input)?;
+    debug!(count = bag_rules.len(), first = ?bag_rules.first(), "parsed");
+
+    let my_bag = params.bag();
+    let graph = build_graph(&bag_rules);
+    debug!(colors = graph.len(), "built graph");
+    let my_id = bag_id(&graph, my_bag, input)?;
+    let my_bag_node = build_tree(&graph, my_id);
+    trace!("containment tree\n{}", my_bag_node);
+
+    let part1 = part1(&graph, &params);
+    let part1_stats = lap.lap();
+    let part2 = part2(&graph, &params);
+    let part2_stats = lap.lap();
+    Ok(Day {
+        visual: Some(format!("{}", my_bag_node)),
+        stats: Parts(part1_stats, part2_stats),
+        ..Day::new(part1, part2)
+    })
+}
+
+/// The answer to a single part, without solving the other
+pub fn solve_part(input: &str, config: &Config, part: Part) -> Result<Answer, SolveError> {
+    let params: Params = config.checked("day7")?;
+    let bag_rules = parse(input)?;
+    let graph = build_graph(&bag_rules);
+    bag_id(&graph, params.bag(), input)?;
+    Ok(match part {
+        Part::One => part1(&graph, &params).answer.into(),
+        Part::Two => part2(&graph, &params).answer.into(),
+    })
+}
+
+pub fn main(config: &Config) -> Result<Day, SolveError> {
+    solve(&get_string("day7.txt"), config)
+}
+
+/// Containment queries for any bag of the rules
+pub struct Repl {
+    graph: Graph<Bag<'static>, usize>,
+}
+
+impl Repl {
+    pub fn open(input: &str, _config: &Config) -> Box<dyn Session> {
+        // Bags borrow from the input, which is kept for as long as the process runs
+        let input: &'static str = Box::leak(input.into());
+        let bag_rules = parse(input).unwrap_or_else(|e| panic!("{}", e));
+        Box::new(Repl {
+            graph: build_graph(&bag_rules),
+        })
+    }
+
+    fn params(&self, args: &[&str]) -> Result<Params, ReplError> {
+        let bag = match *args {
+            [attribute, color] => Bag { attribute, color },
+            _ => return Err(ReplError::Usage),
+        };
+        if self.graph.id(&bag).is_none() {
+            return Err(ReplError::Invalid(format!("no rule mentions {} bags", bag)));
+        }
+        Ok(Params {
+            bag: bag.to_string(),
+        })
+    }
+}
+
+impl Session for Repl {
+    fn commands(&self) -> &'static [(&'static str, &'static str)] {
+        &[
+            (
+                "containers <bag>",
+                "bag colors that can eventually contain it",
+            ),
+            ("inside <bag>", "bags required inside it"),
+            ("tree <bag>", "what it contains, nested"),
+        ]
+    }
+
+    fn eval(&mut self, command: &str, args: &[&str]) -> Result<String, ReplError> {
+        match command {
+            "containers" => Ok(part1(&self.graph, &self.params(args)?).display),
+            "inside" => Ok(part2(&self.graph, &self.params(args)?).display),
+            "tree" => {
+                let params = self.params(args)?;
+                let bag = params.bag();
+                Ok(build_tree(&self.graph, self.graph.id(&bag).unwrap()).to_string())
+            }
+            _ => Err(ReplError::UnknownCommand(command.to_string())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::answer::Answer;
+
+    #[test]
+    fn test_main() {
+        let day = main(&Config::default()).unwrap();
+        assert_eq!(day.answers.0, Answer::Int(246));
+        assert_eq!(day.answers.1, Answer::Int(2976));
+    }
+
+    #[test]
+    fn test_repl() {
+        let mut repl = Repl::open(&get_string("day7.txt"), &Config::default());
+        assert_eq!(
+            repl.eval("inside", &["shiny", "gold"]),
+            Ok("2976 individual bags are required inside my single shiny gold bag.".to_string())
+        );
+        assert!(repl
+            .eval("containers", &["shiny", "gold"])
+            .unwrap()
+            .starts_with("246 bag colors"));
+        assert_eq!(repl.eval("inside", &["gold"]), Err(ReplError::Usage));
+        assert!(matches!(
+            repl.eval("tree", &["plaid", "unicorn"]),
+            Err(ReplError::Invalid(_))
+        ));
+    }
+
+    #[test]
+    fn test_params() {
+        let mut config = Config::default();
+        config.set("day7.bag=gold").unwrap();
+        assert_eq!(
+            config.checked::<Params>("day7").unwrap_err().to_string(),
+            "invalid `day7.bag`: expected `<attribute> <color>`, got `gold`"
+        );
+        config.set("day7.bag=plaid unicorn").unwrap();
+        let error = solve("faded blue bags contain no other bags.", &config).unwrap_err();
+        assert!(matches!(
+            error,
+            SolveError::Parse(e) if e.message == "no rule mentions plaid unicorn bags"
+        ));
+        config.set("day7.bag=gold").unwrap();
+        assert!(matches!(
+            solve("faded blue bags contain no other bags.", &config),
+            Err(SolveError::Config(_))
+        ));
+    }
+
+    #[test]
+    fn test_bag() {
+        let bag = Bag::parse("light red bags").unwrap();
+        assert_eq!(bag.attribute, "light");
+        assert_eq!(bag.color, "red");
+        assert_eq!(
+            Bag::parse("shiny gold").unwrap(),
+            Bag::parse("shiny gold bag").unwrap()
+        );
+        assert!(Bag::parse("gold").is_err());
+    }
+
+    #[test]
+    fn test_bagrule() {
+        let line = "light red bags contain 1 bright white bag, 2 muted yellow bags.";
+        let bagrule = BagRule::parse(lines(line).next().unwrap()).unwrap();
+        assert_eq!(bagrule.outer.attribute, "light");
+        assert_eq!(bagrule.outer.color, "red");
+        assert_eq!(bagrule.contents.len(), 2);
+        assert_eq!(bagrule.contents[0].0, 1);
+        assert_eq!(bagrule.contents[0].1.color, "white");
+        assert_eq!(bagrule.contents[1].0, 2);
+        assert_eq!(bagrule.contents[1].1.attribute, "muted");
+        let empty = parse("faded blue bags contain no other bags.").unwrap();
+        assert!(empty[0].contents.is_empty());
+        let error = parse("faded blue bags contain x blue bags.").unwrap_err();
+        assert_eq!((error.span.col, error.span.len), (25, 1));
+        assert!(parse("faded blue bags contain 2 blue bags").is_err());
+        let error = parse(
+            "\
+faded blue bags contain no other bags.
+shiny gold bags contain 1 dark red bag, 2 faded blue bags.
+dark red bags contain 1 shiny gold bag.",
+        )
+        .unwrap_err();
+        assert_eq!(
+            (error.span.line, error.message.as_str()),
+            (
+                2,
+                "bags contain themselves: shiny gold → dark red → shiny gold"
+            )
+        );
+    }
+
+    #[test]
+    fn test_part1_example() {
+        let bagrules = parse(
+            "\
+light red bags contain 1 bright white bag, 2 muted yellow bags.
+dark orange bags contain 3 bright white bags, 4 muted yellow bags.
+bright white bags contain 1 shiny gold bag.
+muted yellow bags contain 2 shiny gold bags, 9 faded blue bags.
+shiny gold bags contain 1 dark olive bag, 2 vibrant plum bags.
+dark olive bags contain 3 faded blue bags, 4 dotted black bags.
+vibrant plum bags contain 5 faded blue bags, 6 dotted black bags.
+faded blue bags contain no other bags.
+dotted black bags contain no other bags.",
+        )
+        .unwrap();
+        assert_eq!(bagrules.len(), 9);
+    }
+}
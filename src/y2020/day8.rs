@@ -0,0 +1,340 @@
+use super::get_string;
+use crate::answer::Answer;
+use crate::config::Config;
+use crate::memory::Lap;
+use crate::meta::{Meta, Tag};
+use crate::parse::{lines, ParseError, Spanned};
+use crate::repl::{arg, ReplError, Session};
+use crate::{Day, Part, Parts, Solution, SolveError};
+use std::collections::HashSet;
+use std::convert::TryFrom;
+use tracing::{debug, instrument, trace};
+
+#[derive(Debug, PartialEq)]
+pub enum InstructionType {
+    Nop,
+    Acc,
+    Jmp,
+}
+
+#[derive(Debug)]
+pub struct Instruction {
+    pub typ: InstructionType,
+    pub val: i32,
+}
+
+impl Instruction {
+    pub fn parse(line: Spanned<'_>) -> Result<Self, ParseError> {
+        let (typ, val) = line.split_once(' ').ok_or_else(|| {
+            line.error("expected an operation and an argument")
+                .with_hint("expected `<operation> <argument>`, like `acc +1`")
+        })?;
+        let typ = match typ.value {
+            "nop" => InstructionType::Nop,
+            "acc" => InstructionType::Acc,
+            "jmp" => InstructionType::Jmp,
+            op => {
+                return Err(typ
+                    .error(format!("unknown operation `{}`", op))
+                    .with_hint("expected one of nop|acc|jmp"))
+            }
+        };
+        Ok(Instruction {
+            typ,
+            val: val.parse()?,
+        })
+    }
+}
+
+pub struct MachineState {
+    pub pc: u16,
+    pub acc: i32,
+}
+
+#[instrument(skip_all)]
+pub fn parse(input: &str) -> Result<Vec<Instruction>, ParseError> {
+    lines(input).map(Instruction::parse).collect()
+}
+
+impl MachineState {
+    /// Executes the instruction at `pc`, false once `pc` is past the program
+    pub fn step(&mut self, instructions: &[&Instruction]) -> bool {
+        match instructions.get(usize::from(self.pc)) {
+            Some(Instruction {
+                typ: InstructionType::Nop,
+                val: _,
+            }) => {
+                self.pc += 1;
+            }
+            Some(Instruction {
+                typ: InstructionType::Acc,
+                val: v,
+            }) => {
+                self.pc += 1;
+                self.acc += v;
+            }
+            Some(Instruction {
+                typ: InstructionType::Jmp,
+                val: v,
+            }) => {
+                self.pc = u16::try_from(i32::from(self.pc) + v).unwrap();
+            }
+            None => return false,
+        }
+        true
+    }
+}
+
+pub fn calc(instructions: &[&Instruction]) -> (bool, MachineState, HashSet<u16>) {
+    let mut seen = HashSet::<u16>::new();
+    let mut machine_state = MachineState { pc: 0, acc: 0 };
+    let is_infinite_loop: bool = loop {
+        if !seen.insert(machine_state.pc) {
+            break true;
+        }
+        trace!(pc = machine_state.pc, acc = machine_state.acc, "step");
+        if !machine_state.step(instructions) {
+            break false;
+        }
+    };
+    (is_infinite_loop, machine_state, seen)
+}
+
+#[instrument(skip_all)]
+pub fn part1(instructions: &[Instruction]) -> Solution<i32> {
+    let (is_infinite_loop, machine_state, seen) = calc(
+        instructions
+            .iter()
+            .collect::<Vec<&Instruction>>()
+            .as_slice(),
+    );
+    assert!(is_infinite_loop);
+    let steps = vec![
+        format!(
+            "Ran {} distinct instructions until line {} came up again",
+            seen.len(),
+            machine_state.pc + 1
+        ),
+        format!("The accumulator held {} at that point", machine_state.acc),
+    ];
+    Solution::new(
+        machine_state.acc,
+        format!(
+            "Accumulator is {} after {} instructions before looping again.",
+            machine_state.acc,
+            seen.len()
+        ),
+    )
+    .with_steps(steps)
+}
+
+#[instrument(skip_all)]
+pub fn part2(instructions: &[Instruction]) -> Solution<i32> {
+    let modified_instructions = (0..instructions.len())
+        .rev()
+        .filter_map(|idx| match instructions.get(idx) {
+            Some(Instruction {
+                typ: InstructionType::Nop,
+                val: v,
+            }) if *v != 0 => Some((
+                idx,
+                Instruction {
+                    typ: InstructionType::Jmp,
+                    val: *v,
+                },
+            )),
+            Some(Instruction {
+                typ: InstructionType::Jmp,
+                val: v,
+            }) => Some((
+                idx,
+                Instruction {
+                    typ: InstructionType::Nop,
+                    val: *v,
+                },
+            )),
+            _ => None,
+        })
+        .collect::<Vec<(usize, Instruction)>>();
+    let alternatives_solutions = modified_instructions
+        .iter()
+        .map(|(idx, modified)| {
+            instructions
+                .iter()
+                .take(*idx)
+                .chain(std::iter::once(modified))
+                .chain(instructions.iter().skip(idx + 1))
+                .collect::<Vec<&Instruction>>()
+        })
+        .map(|instructions| calc(instructions.as_slice()))
+        .inspect(|(is_infinite_loop, machine_state, _)| {
+            debug!(
+                is_infinite_loop,
+                acc = machine_state.acc,
+                "ran a modified program"
+            )
+        })
+        .collect::<Vec<(bool, MachineState, HashSet<u16>)>>();
+    let attempt = alternatives_solutions
+        .iter()
+        .position(|(is_infinite_loop, _, _)| !is_infinite_loop)
+        .unwrap();
+    let (_, machine_state, seen) = &alternatives_solutions[attempt];
+    let (idx, modified) = &modified_instructions[attempt];
+    let steps = vec![
+        format!(
+            "{} nop or jmp lines could be the corrupted one, tried from the last",
+            modified_instructions.len()
+        ),
+        format!("The first {} changed programs still loop", attempt),
+        format!(
+            "With line {} as `{} {:+}` it exits after {} instructions",
+            idx + 1,
+            format!("{:?}", modified.typ).to_lowercase(),
+            modified.val,
+            seen.len()
+        ),
+        format!("The accumulator held {} on exit", machine_state.acc),
+    ];
+    Solution::new(
+        machine_state.acc,
+        format!(
+            "Accumulator is {} after {} instructions before exiting.",
+            machine_state.acc,
+            seen.len()
+        ),
+    )
+    .with_steps(steps)
+}
+
+pub const META: Meta = Meta {
+    title: "Handheld Halting",
+    tags: &[Tag::Vm],
+    strategies: &["run until a pc repeats, then retry with each nop/jmp swapped"],
+    complexity: "O(n²)",
+};
+
+pub fn solve(input: &str, _config: &Config) -> Result<Day, SolveError> {
+    let mut lap = Lap::new();
+    let instructions = parse(input)?;
+    debug!(count = instructions.len(), first = ?instructions.first(), "parsed");
+    let part1 = part1(&instructions);
+    let part1_stats = lap.lap();
+    let part2 = part2(&instructions);
+    let part2_stats = lap.lap();
+    Ok(Day {
+        stats: Parts(part1_stats, part2_stats),
+        ..Day::new(part1, part2)
+    })
+}
+
+/// The answer to a single part, without solving the other
+pub fn solve_part(input: &str, _config: &Config, part: Part) -> Result<Answer, SolveError> {
+    let instructions = parse(input)?;
+    Ok(match part {
+        Part::One => part1(&instructions).answer.into(),
+        Part::Two => part2(&instructions).answer.into(),
+    })
+}
+
+pub fn main(config: &Config) -> Result<Day, SolveError> {
+    solve(&get_string("day8.txt"), config)
+}
+
+/// Runs the boot code up to any instruction
+pub struct Repl {
+    instructions: Vec<Instruction>,
+}
+
+impl Repl {
+    pub fn open(input: &str, _config: &Config) -> Box<dyn Session> {
+        Box::new(Repl {
+            instructions: parse(input).unwrap_or_else(|e| panic!("{}", e)),
+        })
+    }
+
+    fn run_to(&self, target: u16) -> String {
+        let instructions: Vec<&Instruction> = self.instructions.iter().collect();
+        let mut seen = HashSet::<u16>::new();
+        let mut machine_state = MachineState { pc: 0, acc: 0 };
+        for steps in 0.. {
+            if machine_state.pc == target {
+                return format!(
+                    "Reached pc {} after {} steps, accumulator is {}.",
+                    target, steps, machine_state.acc
+                );
+            }
+            if !seen.insert(machine_state.pc) {
+                return format!(
+                    "Looped back to pc {} after {} steps without reaching pc {}, accumulator is {}.",
+                    machine_state.pc, steps, target, machine_state.acc
+                );
+            }
+            if !machine_state.step(&instructions) {
+                return format!(
+                    "Exited at pc {} after {} steps without reaching pc {}, accumulator is {}.",
+                    machine_state.pc, steps, target, machine_state.acc
+                );
+            }
+        }
+        unreachable!()
+    }
+}
+
+impl Session for Repl {
+    fn commands(&self) -> &'static [(&'static str, &'static str)] {
+        &[("run <pc>", "run from the start until pc is reached")]
+    }
+
+    fn eval(&mut self, command: &str, args: &[&str]) -> Result<String, ReplError> {
+        match command {
+            "run" => Ok(self.run_to(arg(args, 0)?)),
+            _ => Err(ReplError::UnknownCommand(command.to_string())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::answer::Answer;
+
+    #[test]
+    fn test_main() {
+        let day = main(&Config::default()).unwrap();
+        assert_eq!(day.answers.0, Answer::Int(1671));
+        assert_eq!(day.answers.1, Answer::Int(892));
+    }
+
+    #[test]
+    fn test_repl() {
+        let mut repl = Repl::open(
+            "nop +0\nacc +1\njmp +4\nacc +3\njmp -3\nacc -99\nacc +1\njmp -4\nacc +6",
+            &Config::default(),
+        );
+        assert_eq!(
+            repl.eval("run", &["6"]),
+            Ok("Reached pc 6 after 3 steps, accumulator is 1.".to_string())
+        );
+        assert_eq!(
+            repl.eval("run", &["5"]),
+            Ok(
+                "Looped back to pc 1 after 7 steps without reaching pc 5, accumulator is 5."
+                    .to_string()
+            )
+        );
+        assert_eq!(repl.eval("run", &["-1"]), Err(ReplError::Usage));
+    }
+
+    #[test]
+    fn test_parse_instruction() {
+        let instruction: Instruction = parse("acc -99").unwrap().drain(..1).next().unwrap();
+        assert_eq!(instruction.typ, InstructionType::Acc);
+        assert_eq!(instruction.val, -99);
+        assert_eq!(parse("jmp +4").unwrap()[0].val, 4);
+        let line = lines("acc +1\nhop +1").nth(1).unwrap();
+        let error = Instruction::parse(line).unwrap_err();
+        assert_eq!((error.span.line, error.span.col), (2, 1));
+        assert_eq!(error.hint.unwrap(), "expected one of nop|acc|jmp");
+    }
+}
@@ -0,0 +1,215 @@
+use itertools::Itertools;
+use serde::Deserialize;
+use tracing::{debug, instrument};
+
+use super::get_string;
+use crate::answer::Answer;
+use crate::config::{self, Config};
+use crate::memory::Lap;
+use crate::meta::Meta;
+use crate::parse::{numbers, ParseError};
+use crate::{Day, Part, Parts, Solution, SolveError};
+
+#[instrument(skip_all)]
+pub fn parse(input: &str) -> Result<Vec<usize>, ParseError> {
+    numbers(input)
+}
+
+/// Numbers following a preamble of `preamble` of them
+pub fn parse_with_preamble(input: &str, preamble: usize) -> Result<Vec<usize>, ParseError> {
+    let numbers = parse(input)?;
+    if numbers.len() <= preamble {
+        return Err(ParseError::at_end(
+            input,
+            format!("only {} numbers, none after the preamble", numbers.len()),
+        )
+        .with_hint(format!(
+            "expected more than the {} numbers of the preamble",
+            preamble
+        )));
+    }
+    Ok(numbers)
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Params {
+    pub preamble: usize,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Params { preamble: 25 }
+    }
+}
+
+impl config::Params for Params {
+    fn validate(&self) -> Result<(), (&'static str, String)> {
+        match self.preamble {
+            0 | 1 => Err(("preamble", "two numbers are needed for a sum".to_string())),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// First number that is not the sum of two of the `preamble` numbers before it
+pub fn first_invalid(numbers: &[usize], preamble: usize) -> usize {
+    numbers
+        .iter()
+        .enumerate()
+        .skip(preamble)
+        .filter_map(|(idx, num)| {
+            let skip_count = idx.saturating_sub(preamble);
+            let prev = numbers.iter().cloned().skip(skip_count).take(preamble);
+            if !prev
+                .combinations(2)
+                .map(|v| v.into_iter().sum::<usize>())
+                .any(|s| s == *num)
+            {
+                return Some(*num);
+            }
+            None
+        })
+        .next()
+        .unwrap()
+}
+
+#[instrument(skip_all)]
+pub fn part1(numbers: &[usize], params: &Params) -> Solution<usize> {
+    let sum_answer = first_invalid(numbers, params.preamble);
+    let steps = vec![
+        format!(
+            "Checked each number against the sums of pairs of the {} before it",
+            params.preamble
+        ),
+        format!(
+            "{} on line {} is the first that no pair sums to",
+            sum_answer,
+            numbers.iter().position(|&n| n == sum_answer).unwrap() + 1
+        ),
+    ];
+    Solution::new(
+        sum_answer,
+        format!("{} is the first number that is not a sum", sum_answer),
+    )
+    .with_steps(steps)
+}
+
+#[instrument(skip_all)]
+pub fn part2(numbers: &[usize], params: &Params) -> Solution<usize> {
+    let sum_answer = first_invalid(numbers, params.preamble);
+    let mut range = numbers
+        .iter()
+        .enumerate()
+        .filter_map(|(start_idx, num)| {
+            let mut end_idx = start_idx;
+            let mut num = *num;
+            while num < sum_answer {
+                end_idx += 1;
+                num += numbers.get(end_idx).unwrap();
+            }
+            if num == sum_answer {
+                Some(numbers.get(start_idx..=end_idx).unwrap())
+            } else {
+                None
+            }
+        })
+        .next()
+        .unwrap()
+        .to_vec();
+    range.sort_unstable();
+    debug!(?range, "contiguous range summing to the invalid number");
+    let sum_small_big = range.first().unwrap() + range.last().unwrap();
+    let steps = vec![
+        format!("{} is the first number that is not a sum", sum_answer),
+        format!(
+            "Grew a run from each number until it reached {}, a run of {} numbers hits it exactly",
+            sum_answer,
+            range.len()
+        ),
+        format!(
+            "Its smallest and largest: {} + {} = {}",
+            range.first().unwrap(),
+            range.last().unwrap(),
+            sum_small_big
+        ),
+    ];
+    Solution::new(
+        sum_small_big,
+        format!("{} = min + max of {} numbers", sum_small_big, range.len()),
+    )
+    .with_steps(steps)
+}
+
+pub const META: Meta = Meta {
+    title: "Encoding Error",
+    tags: &[],
+    strategies: &[
+        "pair sums over the sliding preamble, then a contiguous range grown from each start",
+    ],
+    complexity: "O(n·p²) and O(n²)",
+};
+
+pub fn solve(input: &str, config: &Config) -> Result<Day, SolveError> {
+    let mut lap = Lap::new();
+    let params: Params = config.checked("day9")?;
+    let numbers = parse_with_preamble(input, params.preamble)?;
+    debug!(count = numbers.len(), first = ?numbers.first(), "parsed");
+    let part1 = part1(&numbers, &params);
+    let part1_stats = lap.lap();
+    let part2 = part2(&numbers, &params);
+    let part2_stats = lap.lap();
+    Ok(Day {
+        stats: Parts(part1_stats, part2_stats),
+        ..Day::new(part1, part2)
+    })
+}
+
+/// The answer to a single part, without solving the other
+pub fn solve_part(input: &str, config: &Config, part: Part) -> Result<Answer, SolveError> {
+    let params: Params = config.checked("day9")?;
+    let numbers = parse_with_preamble(input, params.preamble)?;
+    Ok(match part {
+        Part::One => part1(&numbers, &params).answer.into(),
+        Part::Two => part2(&numbers, &params).answer.into(),
+    })
+}
+
+pub fn main(config: &Config) -> Result<Day, SolveError> {
+    solve(&get_string("day9.txt"), config)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::answer::Answer;
+
+    #[test]
+    fn test_main() {
+        let day = main(&Config::default()).unwrap();
+        assert_eq!(day.answers.0, Answer::Int(50047984));
+        assert_eq!(day.answers.1, Answer::Int(5407707));
+    }
+
+    #[test]
+    fn test_example() {
+        let numbers = parse(
+            "35\n20\n15\n25\n47\n40\n62\n55\n65\n95\n102\n117\n150\n182\n127\n219\n299\n277\n309\n576",
+        )
+        .unwrap();
+        let params = Params { preamble: 5 };
+        assert_eq!(part1(&numbers, &params).answer, 127);
+        assert_eq!(part2(&numbers, &params).answer, 62);
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(parse_with_preamble("1\n2\n3", 2).unwrap(), [1, 2, 3]);
+        let error = parse_with_preamble("1\n2\n3", 3).unwrap_err();
+        assert_eq!(
+            (error.span.line, error.message.as_str()),
+            (4, "only 3 numbers, none after the preamble")
+        );
+        assert!(solve("1\n2\n3", &Config::default()).is_err());
+    }
+}
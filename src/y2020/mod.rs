@@ -0,0 +1,117 @@
+use crate::Puzzle;
+
+#[cfg(feature = "day1")]
+pub mod day1;
+#[cfg(feature = "day10")]
+pub mod day10;
+#[cfg(feature = "day11")]
+pub mod day11;
+#[cfg(feature = "day12")]
+pub mod day12;
+#[cfg(feature = "day13")]
+pub mod day13;
+#[cfg(feature = "day14")]
+pub mod day14;
+#[cfg(feature = "day15")]
+pub mod day15;
+#[cfg(feature = "day16")]
+pub mod day16;
+#[cfg(feature = "day17")]
+pub mod day17;
+#[cfg(feature = "day2")]
+pub mod day2;
+#[cfg(feature = "day3")]
+pub mod day3;
+#[cfg(feature = "day4")]
+pub mod day4;
+#[cfg(feature = "day5")]
+pub mod day5;
+#[cfg(feature = "day6")]
+pub mod day6;
+#[cfg(feature = "day7")]
+pub mod day7;
+#[cfg(feature = "day8")]
+pub mod day8;
+#[cfg(feature = "day9")]
+pub mod day9;
+
+pub const YEAR: u16 = 2020;
+
+#[allow(unused_macros)]
+macro_rules! puzzle {
+    ($day:literal, $module:ident, $input:literal $(, $extra:ident)*) => {{
+        let puzzle = Puzzle {
+            day: $day,
+            main: $module::main,
+            solve: $module::solve,
+            part: $module::solve_part,
+            input: $input,
+            meta: &$module::META,
+            check: |input| $module::parse(input).map(drop),
+            params: |config| config.no_params(concat!("day", $day)),
+            repl: None,
+        };
+        $(let puzzle = puzzle!(@$extra puzzle, $day, $module);)*
+        puzzle
+    }};
+    (@params $puzzle:ident, $day:literal, $module:ident) => {
+        Puzzle {
+            params: |config| {
+                config
+                    .checked::<$module::Params>(concat!("day", $day))
+                    .map(drop)
+            },
+            ..$puzzle
+        }
+    };
+    (@repl $puzzle:ident, $day:literal, $module:ident) => {
+        Puzzle {
+            repl: Some($module::Repl::open),
+            ..$puzzle
+        }
+    };
+}
+
+/// The days enabled by their `dayN` cargo features, in order
+pub fn get_days() -> Vec<Puzzle> {
+    vec![
+        #[cfg(feature = "day1")]
+        puzzle!(1, day1, "day1.txt", params),
+        #[cfg(feature = "day2")]
+        puzzle!(2, day2, "day2.txt"),
+        #[cfg(feature = "day3")]
+        puzzle!(3, day3, "day3.txt", params),
+        #[cfg(feature = "day4")]
+        puzzle!(4, day4, "day4.txt"),
+        #[cfg(feature = "day5")]
+        puzzle!(5, day5, "day5.txt"),
+        #[cfg(feature = "day6")]
+        puzzle!(6, day6, "day6.txt"),
+        #[cfg(feature = "day7")]
+        puzzle!(7, day7, "day7.txt", params, repl),
+        #[cfg(feature = "day8")]
+        puzzle!(8, day8, "day8.txt", repl),
+        #[cfg(feature = "day9")]
+        puzzle!(9, day9, "day9.txt", params),
+        #[cfg(feature = "day10")]
+        puzzle!(10, day10, "day10.txt"),
+        #[cfg(feature = "day11")]
+        puzzle!(11, day11, "day11.txt", params, repl),
+        #[cfg(feature = "day12")]
+        puzzle!(12, day12, "day12.txt"),
+        #[cfg(feature = "day13")]
+        puzzle!(13, day13, "day13.txt", repl),
+        #[cfg(feature = "day14")]
+        puzzle!(14, day14, "day14.txt"),
+        #[cfg(feature = "day15")]
+        puzzle!(15, day15, "day15.txt", params, repl),
+        #[cfg(feature = "day16")]
+        puzzle!(16, day16, "day16_am.txt", params),
+        #[cfg(feature = "day17")]
+        puzzle!(17, day17, "day17.txt", params),
+    ]
+}
+
+pub fn get_string(name: &str) -> String {
+    crate::get_input(YEAR, name)
+}
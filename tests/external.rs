@@ -0,0 +1,104 @@
+//! The external solver protocol, against the shell-script solvers in `tests/external`
+
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+use advent2020::answer::Answer;
+use advent2020::config::Config;
+use advent2020::external::{self, ExternalError, Request, Verdict};
+
+fn root() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+}
+
+fn solver(name: &str) -> String {
+    format!("sh {}", root().join("tests/external").join(name).display())
+}
+
+fn request(day: usize) -> Request {
+    let mut config = Config::default();
+    config.set("day1.target=2020").unwrap();
+    Request {
+        year: 2020,
+        day,
+        params: config.table("day1"),
+    }
+}
+
+fn input() -> String {
+    fs::read_to_string(root().join("inputs/2020/day1.txt")).unwrap()
+}
+
+#[test]
+fn test_protocol() {
+    let run = external::run(&solver("echo.sh"), &request(1), &input()).unwrap();
+    assert_eq!(
+        run.answers.0.unwrap(),
+        Answer::from(r#"{"day":1,"params":{"target":2020},"year":2020}"#)
+    );
+    assert_eq!(
+        run.answers.1.unwrap(),
+        Answer::from(input().lines().count())
+    );
+}
+
+#[test]
+fn test_day1_solver() {
+    let ours = advent2020::y2020::day1::solve(&input(), &Config::default()).unwrap();
+    let theirs = external::run(&solver("day1.sh"), &request(1), &input()).unwrap();
+    assert_eq!(
+        Verdict::new(&ours.answers.0, &theirs.answers.0),
+        Verdict::Same
+    );
+    assert_eq!(
+        Verdict::new(&ours.answers.1, &theirs.answers.1),
+        Verdict::Same
+    );
+
+    let wrong = external::run(&solver("wrong.sh"), &request(1), &input()).unwrap();
+    assert_eq!(
+        Verdict::new(&ours.answers.0, &wrong.answers.0),
+        Verdict::Differs(Answer::Int(42))
+    );
+    assert_eq!(
+        Verdict::new(&ours.answers.1, &wrong.answers.1),
+        Verdict::Unanswered
+    );
+}
+
+#[test]
+fn test_failures() {
+    let run = |command: &str| external::run(command, &request(1), "").unwrap_err();
+    match run(&solver("fail.sh")) {
+        ExternalError::Failed(Some(3), stderr) => assert_eq!(stderr, "no idea\n"),
+        e => panic!("{:?}", e),
+    }
+    assert!(matches!(run(&solver("garbage.sh")), ExternalError::Json(_)));
+    assert!(matches!(run(" "), ExternalError::Command));
+    assert!(matches!(run("./no-such-solver"), ExternalError::Io(_)));
+}
+
+#[test]
+fn test_compare_command() {
+    let compare = |script: &str| {
+        Command::new(env!("CARGO_BIN_EXE_advent2020"))
+            .current_dir(root())
+            .args(["--no-cache", "--no-history", "compare", "--external"])
+            .arg(solver(script))
+            .args(["--set", "inputs/2020", "--set", "tests/external", "1"])
+            .output()
+            .unwrap()
+    };
+    let output = compare("day1.sh");
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(output.status.success(), "{}", stdout);
+    assert_eq!(stdout.matches("same").count(), 2, "{}", stdout);
+    assert!(stdout.contains("No day1.txt in this set"), "{}", stdout);
+
+    let output = compare("wrong.sh");
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(!output.status.success(), "{}", stdout);
+    assert!(stdout.contains("differs, theirs is 42"), "{}", stdout);
+    assert!(stdout.contains("unanswered"), "{}", stdout);
+}
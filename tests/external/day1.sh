@@ -0,0 +1,13 @@
+#!/bin/sh
+# Day 1 in awk, the target comes from the request's params when set
+target=$(printf '%s' "$1" | sed -n 's/.*"target":\([0-9]*\).*/\1/p')
+awk -v target="${target:-2020}" '
+{ n[NR] = $1; seen[$1] = 1 }
+END {
+    for (i = 1; i <= NR && !p1; i++)
+        if (seen[target - n[i]]) p1 = n[i] * (target - n[i])
+    for (i = 1; i <= NR && !p2; i++)
+        for (j = i + 1; j <= NR && !p2; j++)
+            if (seen[target - n[i] - n[j]]) p2 = n[i] * n[j] * (target - n[i] - n[j])
+    printf "{\"part1\": %d, \"part2\": %d}\n", p1, p2
+}'
@@ -0,0 +1,3 @@
+#!/bin/sh
+# Answers with the request it was given and the number of input lines
+printf '{"part1": %s, "part2": "%s"}\n' "$1" "$(wc -l | tr -d ' ')"
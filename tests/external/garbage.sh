@@ -0,0 +1,2 @@
+#!/bin/sh
+echo "part 1 is 42"
@@ -0,0 +1,4 @@
+#!/bin/sh
+# Gets part 1 wrong and does not answer part 2
+cat > /dev/null
+echo '{"part1": 42}'
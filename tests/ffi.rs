@@ -0,0 +1,95 @@
+//! The C ABI, called from a C program built with the system compiler
+//!
+//! Rewrite the header after changing the ABI with
+//! `ADVENT_UPDATE_HEADER=1 cargo test --features ffi --test ffi`.
+
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use advent2020::ffi;
+
+const HEADER: &str = "include/advent2020.h";
+
+fn root() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+}
+
+#[test]
+fn test_header_up_to_date() {
+    let path = root().join(HEADER);
+    if env::var_os("ADVENT_UPDATE_HEADER").is_some() {
+        fs::write(&path, ffi::header()).unwrap();
+    }
+    assert_eq!(
+        fs::read_to_string(&path).unwrap(),
+        ffi::header(),
+        "{} is stale, set ADVENT_UPDATE_HEADER=1 to rewrite it",
+        HEADER
+    );
+}
+
+/// Builds the cdylib in its own target directory, the outer build holds the lock on ours
+fn build_library() -> PathBuf {
+    let target_dir = root().join("target").join("ffi");
+    let status = Command::new(env!("CARGO"))
+        .current_dir(root())
+        .args([
+            "rustc",
+            "--lib",
+            "--crate-type",
+            "cdylib",
+            "--features",
+            "ffi",
+        ])
+        .arg("--target-dir")
+        .arg(&target_dir)
+        .status()
+        .unwrap();
+    assert!(status.success(), "building the cdylib failed");
+    target_dir.join("debug")
+}
+
+#[test]
+fn test_c_program() {
+    let lib_dir = build_library();
+    let exe = lib_dir.join("solve");
+    let status = Command::new("cc")
+        .arg(root().join("tests/ffi/solve.c"))
+        .arg("-I")
+        .arg(root().join("include"))
+        .arg("-L")
+        .arg(&lib_dir)
+        .args(["-ladvent2020", "-o"])
+        .arg(&exe)
+        .status()
+        .unwrap();
+    assert!(status.success(), "compiling the C program failed");
+
+    // Cargo's own library path may hold other builds of the crate
+    let output = Command::new(&exe)
+        .env("LD_LIBRARY_PATH", &lib_dir)
+        .arg(root().join("inputs/2020/day1.txt"))
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert_eq!(
+        String::from_utf8(output.stdout).unwrap(),
+        "\
+part 1: 0 158916
+part 2: 0 165795564
+day 99: 2
+part 3: 3
+null input: 1
+invalid utf-8: 4
+garbage: 7
+day 7 cycle: 7
+tiny buffer: 6 needs 7
+"
+    );
+}
@@ -0,0 +1,62 @@
+/* Solves day 1 of the input file given as the only argument, and checks error codes */
+
+#include <stdio.h>
+#include <stdlib.h>
+#include <string.h>
+
+#include "advent2020.h"
+
+static char *read_file(const char *path, size_t *len) {
+    FILE *file = fopen(path, "rb");
+    if (!file) {
+        return NULL;
+    }
+    fseek(file, 0, SEEK_END);
+    *len = (size_t)ftell(file);
+    rewind(file);
+    char *data = malloc(*len);
+    if (data && fread(data, 1, *len, file) != *len) {
+        free(data);
+        data = NULL;
+    }
+    fclose(file);
+    return data;
+}
+
+int main(int argc, char **argv) {
+    if (argc != 2) {
+        fprintf(stderr, "usage: %s <day 1 input>\n", argv[0]);
+        return 2;
+    }
+    size_t len;
+    char *input = read_file(argv[1], &len);
+    if (!input) {
+        perror(argv[1]);
+        return 2;
+    }
+    char data[64];
+    AdventBuffer out = {data, sizeof data, 0};
+
+    for (uint32_t part = 1; part <= 2; part++) {
+        int32_t status = advent_solve(1, part, (const uint8_t *)input, len, &out);
+        printf("part %u: %d %s\n", part, status, status == ADVENT_OK ? out.data : "");
+    }
+
+    printf("day 99: %d\n", advent_solve(99, 1, (const uint8_t *)input, len, &out));
+    printf("part 3: %d\n", advent_solve(1, 3, (const uint8_t *)input, len, &out));
+    printf("null input: %d\n", advent_solve(1, 1, NULL, 0, &out));
+    const uint8_t invalid[] = {0xff, 0xfe};
+    printf("invalid utf-8: %d\n", advent_solve(1, 1, invalid, sizeof invalid, &out));
+    const char *garbage = "not a number\n";
+    printf("garbage: %d\n",
+           advent_solve(1, 1, (const uint8_t *)garbage, strlen(garbage), &out));
+    const char *cycle = "shiny gold bags contain 1 dark red bag.\n"
+                        "dark red bags contain 1 shiny gold bag.\n";
+    printf("day 7 cycle: %d\n", advent_solve(7, 1, (const uint8_t *)cycle, strlen(cycle), &out));
+    AdventBuffer tiny = {data, 4, 0};
+    int32_t status = advent_solve(1, 1, (const uint8_t *)input, len, &tiny);
+    printf("tiny buffer: %d needs %zu\n", status, tiny.len);
+
+    free(input);
+    return 0;
+}
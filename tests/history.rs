@@ -0,0 +1,49 @@
+//! Runs logged to a history file, and the `history` subcommand reading it back
+
+use std::fs;
+use std::path::Path;
+use std::process::{Command, Output};
+
+use advent2020::history::History;
+
+fn run(history: &Path, args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_advent2020"))
+        .current_dir(env!("CARGO_MANIFEST_DIR"))
+        .arg("--history-file")
+        .arg(history)
+        .args(args)
+        .output()
+        .unwrap()
+}
+
+#[test]
+fn test_history() {
+    let dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join("history");
+    let _ = fs::remove_dir_all(&dir);
+    let path = dir.join("history.jsonl");
+    for _ in 0..2 {
+        assert!(run(&path, &["--no-cache", "1"]).status.success());
+    }
+    assert!(run(&path, &["--no-cache", "--no-history", "1"])
+        .status
+        .success());
+
+    let records = History::new(&path).read().unwrap();
+    assert_eq!(records.len(), 4);
+    assert_eq!(
+        records.iter().map(|r| (r.day, r.part)).collect::<Vec<_>>(),
+        [(1, 1), (1, 2), (1, 1), (1, 2)]
+    );
+    assert!(records.iter().all(|r| !r.cached && r.input.len() == 64));
+
+    let output = run(&path, &["history", "1", "2"]);
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(output.status.success(), "{}", stdout);
+    assert_eq!(stdout.matches("2 measured").count(), 2, "{}", stdout);
+    assert!(
+        stdout.contains(&records[0].answer.to_string()),
+        "{}",
+        stdout
+    );
+    assert!(stdout.contains("No runs yet"), "{}", stdout);
+}
@@ -1,7 +1,9 @@
-use advent2020::day16::{Rule, RuleStr, parse_notes};
+use advent2020::y2020::day16::{Rule, RuleStr, parse};
 
 use proptest::prelude::*;
 
+type RuleRange = ((usize, usize), (usize, usize));
+
 prop_compose! {
     fn arbitrary_rule(max: usize)
             (start in 0usize..max, d1 in 0usize..max, d2 in 0usize..max, d3 in 0usize..max)
@@ -20,20 +22,20 @@ proptest! {
 prop_compose! {
     fn arbitrary_rule_range(max: usize)
             (start in 0usize..max, d1 in 0usize..max, d2 in 0usize..max, d3 in 0usize..max)
-                -> ((usize, usize), (usize, usize)) {
+                -> RuleRange {
         ((start, start + d1), (start + d1 + d2, start + d1 + d2 + d3))
     }
 }
 
-fn format_rule_range(idx: usize, rule: ((usize, usize), (usize, usize))) -> String {
+fn format_rule_range(idx: usize, rule: RuleRange) -> String {
     format!(
         "Rule {}: {}-{} or {}-{}",
         idx, rule.0 .0, rule.0 .1, rule.1 .0, rule.1 .1,
     )
 }
 
-fn arbitrary_fields(rules: &[((usize, usize), (usize, usize))]) -> impl Strategy<Value = Vec<usize>> {
-    rules.into_iter().map(|rule| {
+fn arbitrary_fields(rules: &[RuleRange]) -> impl Strategy<Value = Vec<usize>> {
+    rules.iter().map(|rule| {
         println!("{:#?}", rule);
         prop_oneof![
             (rule.0.0..=rule.0.1),
@@ -86,6 +88,6 @@ proptest! {
     #[test]
     fn notes_parse(note_str in arbitrary_notes()) {
         println!("{}", note_str);
-        parse_notes(&note_str);
+        parse(&note_str).unwrap();
     }
 }
@@ -0,0 +1,76 @@
+use advent2020::numtheory::*;
+
+use proptest::prelude::*;
+
+proptest! {
+    #[test]
+    fn gcd_divides_both(a in -10_000i64..10_000, b in -10_000i64..10_000) {
+        let g = gcd(a, b).unwrap();
+        if g == 0 {
+            prop_assert_eq!((a, b), (0, 0));
+        } else {
+            prop_assert_eq!(a % g, 0);
+            prop_assert_eq!(b % g, 0);
+            prop_assert_eq!(gcd(a / g, b / g), Some(1));
+        }
+    }
+
+    #[test]
+    fn lcm_times_gcd(a in 1u64..100_000, b in 1u64..100_000) {
+        prop_assert_eq!(lcm(a, b).unwrap() * gcd(a, b).unwrap(), a * b);
+    }
+
+    #[test]
+    fn extended_gcd_identity(a in -100_000i64..100_000, b in -100_000i64..100_000) {
+        let (g, x, y) = extended_gcd(a, b).unwrap();
+        prop_assert_eq!(a * x + b * y, g);
+        prop_assert_eq!(Some(g), gcd(a, b));
+    }
+
+    #[test]
+    fn mod_inverse_is_inverse(a in any::<u32>(), m in 1u32..) {
+        match mod_inverse(a, m) {
+            Some(x) => {
+                prop_assert!(x < m);
+                prop_assert_eq!(u64::from(a) * u64::from(x) % u64::from(m), 1 % u64::from(m));
+            }
+            None => prop_assert_ne!(gcd(a, m), Some(1)),
+        }
+    }
+
+    #[test]
+    fn mod_pow_matches_repeated_multiplication(base in any::<u16>(), exp in 0u32..200, m in 1u32..=u32::from(u16::MAX)) {
+        let naive = (0..exp).fold(1 % m, |acc, _| acc * u32::from(base) % m);
+        prop_assert_eq!(mod_pow(u32::from(base), exp, m), Some(naive));
+    }
+
+    #[test]
+    fn crt_solves_every_congruence(congruences in prop::collection::vec((any::<i16>(), 1i64..40), 0..4)) {
+        let congruences: Vec<(i64, i64)> = congruences.into_iter().map(|(r, m)| (i64::from(r), m)).collect();
+        let modulus = congruences.iter().fold(1, |acc, &(_, m)| lcm(acc, m).unwrap());
+        let brute = (0..modulus).find(|x| congruences.iter().all(|&(r, m)| (x - r).rem_euclid(m) == 0));
+        prop_assert_eq!(crt(&congruences), brute.map(|x| (x, modulus)));
+    }
+
+    #[test]
+    fn discrete_log_is_smallest(base in 1u64..500, exp in 0u64..2000, m in 2u64..2000) {
+        let target = mod_pow(base, exp, m).unwrap();
+        let x = discrete_log(base, target, m);
+        let brute = (0..=exp).find(|&x| mod_pow(base, x, m) == Some(target));
+        if gcd(base, m) == Some(1) {
+            prop_assert_eq!(x, brute);
+        } else if let Some(x) = x {
+            prop_assert_eq!(mod_pow(base, x, m), Some(target));
+        }
+    }
+
+    #[test]
+    fn no_panics_on_overflow(a in any::<i64>(), b in any::<i64>(), m in any::<i64>()) {
+        let _ = gcd(a, b);
+        let _ = lcm(a, b);
+        let _ = extended_gcd(a, b);
+        let _ = mod_pow(a, b, m);
+        let _ = mod_inverse(a, m);
+        let _ = crt(&[(a, m), (b, m / 2)]);
+    }
+}
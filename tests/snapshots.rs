@@ -0,0 +1,138 @@
+//! Snapshots of the human-facing output of every day
+//!
+//! Snapshots live in `tests/snapshots/`, review changes with `cargo insta review`
+//! or accept them all with `INSTA_UPDATE=always cargo test --test snapshots`.
+
+// Every snapshot is behind its day's feature, and the helpers are only needed by them
+#![cfg(any(
+    feature = "day1",
+    feature = "day2",
+    feature = "day3",
+    feature = "day4",
+    feature = "day5",
+    feature = "day6",
+    feature = "day7",
+    feature = "day8",
+    feature = "day9",
+    feature = "day10",
+    feature = "day11",
+    feature = "day12",
+    feature = "day13",
+    feature = "day14",
+    feature = "day15",
+    feature = "day16",
+    feature = "day17"
+))]
+
+use advent2020::config::Config;
+use advent2020::y2020::{self, get_string};
+use advent2020::Day;
+use regex::{Captures, Regex};
+
+/// Sorts the parts of the output whose order comes from a `HashSet` or `HashMap`
+///
+/// These are the characters of `'...'` groups (day 6) and `a×b×c` products (day 16).
+fn normalise(s: &str) -> String {
+    let quoted = Regex::new(r"'([^']*)'").unwrap();
+    let product = Regex::new(r"\d+(×\d+)+").unwrap();
+    let s = quoted.replace_all(s, |caps: &Captures| {
+        let mut chars: Vec<char> = caps[1].chars().collect();
+        chars.sort_unstable();
+        format!("'{}'", chars.into_iter().collect::<String>())
+    });
+    let s = product.replace_all(&s, |caps: &Captures| {
+        let mut factors: Vec<u64> = caps[0].split('×').map(|f| f.parse().unwrap()).collect();
+        factors.sort_unstable();
+        factors
+            .iter()
+            .map(u64::to_string)
+            .collect::<Vec<_>>()
+            .join("×")
+    });
+    s.into_owned()
+}
+
+fn output(day: &Day) -> String {
+    let mut output = format!("Part 1: {}\nPart 2: {}\n", day.display.0, day.display.1);
+    if let Some(visual) = &day.visual {
+        output += &format!("\n{}\n", visual);
+    }
+    output
+}
+
+fn config(params: &[&str]) -> Config {
+    let mut config = Config::default();
+    for param in params {
+        config.set(param).unwrap();
+    }
+    config
+}
+
+macro_rules! snapshot_day {
+    ($name:ident, $input:expr) => {
+        snapshot_day!($name, $input, &[]);
+    };
+    ($name:ident, $input:expr, $params:expr) => {
+        #[test]
+        fn $name() {
+            let day = y2020::$name::solve(&$input, &config($params)).unwrap();
+            insta::assert_snapshot!(normalise(&output(&day)));
+        }
+    };
+}
+
+#[cfg(feature = "day1")]
+snapshot_day!(day1, get_string("day1.txt"));
+#[cfg(feature = "day2")]
+snapshot_day!(day2, get_string("day2.txt"));
+#[cfg(feature = "day3")]
+snapshot_day!(day3, get_string("day3.txt"));
+#[cfg(feature = "day4")]
+snapshot_day!(day4, get_string("day4.txt"));
+#[cfg(feature = "day5")]
+snapshot_day!(day5, get_string("day5.txt"));
+#[cfg(feature = "day6")]
+snapshot_day!(day6, get_string("day6.txt"));
+#[cfg(feature = "day7")]
+snapshot_day!(day7, get_string("day7.txt"));
+#[cfg(feature = "day8")]
+snapshot_day!(day8, get_string("day8.txt"));
+#[cfg(feature = "day9")]
+snapshot_day!(day9, get_string("day9.txt"));
+#[cfg(feature = "day10")]
+snapshot_day!(day10, get_string("day10.txt"));
+#[cfg(feature = "day12")]
+snapshot_day!(day12, get_string("day12.txt"));
+#[cfg(feature = "day13")]
+snapshot_day!(day13, get_string("day13.txt"));
+#[cfg(feature = "day14")]
+snapshot_day!(day14, get_string("day14.txt"));
+#[cfg(feature = "day16")]
+snapshot_day!(day16, get_string("day16.txt"));
+
+// The full inputs of these days are too slow for debug builds
+#[cfg(feature = "day11")]
+snapshot_day!(
+    day11,
+    "\
+L.LL.LL.LL
+LLLLLLL.LL
+L.L.L..L..
+LLLL.LL.LL
+L.LL.LL.LL
+L.LLLLL.LL
+..L.L.....
+LLLLLLLLLL
+L.LLLLLL.L
+L.LLLLL.LL"
+);
+#[cfg(feature = "day15")]
+snapshot_day!(day15, get_string("day15.txt"), &["day15.part2_turn=2020"]);
+#[cfg(feature = "day17")]
+snapshot_day!(day17, ".#.\n..#\n###", &["day17.part2_dimensions=3"]);
+
+#[test]
+fn test_normalise() {
+    assert_eq!(normalise("3 = 'cab' + 'b' + ''"), "3 = 'abc' + 'b' + ''");
+    assert_eq!(normalise("60 = 5×12×1, 7"), "60 = 1×5×12, 7");
+}
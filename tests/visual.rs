@@ -0,0 +1,38 @@
+//! Day 7's visual, printed or written to a directory
+
+use std::fs;
+use std::path::Path;
+use std::process::{Command, Output};
+
+fn run(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_advent2020"))
+        .current_dir(env!("CARGO_MANIFEST_DIR"))
+        .args(["--no-cache", "--no-history", "7"])
+        .args(args)
+        .output()
+        .unwrap()
+}
+
+#[test]
+fn test_visual_modes() {
+    let stdout = String::from_utf8(run(&["--visual"]).stdout).unwrap();
+    assert!(
+        stdout.contains("\nshiny gold\n  5 dark salmon\n"),
+        "{}",
+        stdout
+    );
+
+    let dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join("visuals");
+    let _ = fs::remove_dir_all(&dir);
+    let output = run(&["--visual-dir", dir.to_str().unwrap()]);
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(output.status.success(), "{}", stdout);
+    assert!(!stdout.contains("dark salmon"), "{}", stdout);
+    assert!(stdout.contains("Visual written to"), "{}", stdout);
+    let visual = fs::read_to_string(dir.join("day7.txt")).unwrap();
+    assert!(
+        visual.starts_with("shiny gold\n  5 dark salmon\n"),
+        "{}",
+        visual
+    );
+}